@@ -0,0 +1,30 @@
+#!/bin/sh
+
+# Pass in a different image to test against MariaDB.
+# i.e. `./scripts/start-mysql.sh mariadb:11`
+IMAGE=${1:-mysql:8.0}
+
+CLIENT=mysql
+case $IMAGE in
+    mariadb*) CLIENT=mariadb ;;
+esac
+
+docker run --name assert-migrator-reversible__mysql \
+    --rm \
+    --env MYSQL_USER=user \
+    --env MYSQL_DATABASE=assert-migrator-reversible \
+    --env MYSQL_PASSWORD=password \
+    --env MYSQL_ROOT_PASSWORD=password \
+    -p 3306:3306 \
+    --detach $IMAGE
+
+sleep 20
+
+docker exec -i assert-migrator-reversible__mysql \
+    $CLIENT --user=root --password=password << EOM
+  CREATE DATABASE \`assert-migrator-reversible--1\`;
+  CREATE DATABASE \`assert-migrator-reversible--2\`;
+  CREATE DATABASE \`assert-migrator-reversible--3\`;
+  CREATE DATABASE \`assert-migrator-reversible--4\`;
+  GRANT ALL PRIVILEGES ON *.* TO 'user'@'%';
+EOM
@@ -14,4 +14,34 @@ PGPASSWORD=password psql --host=localhost --port=5432 --dbname=assert-migrator-r
   CREATE DATABASE "assert-migrator-reversible--1";
   CREATE DATABASE "assert-migrator-reversible--2";
   CREATE DATABASE "assert-migrator-reversible--3";
+  CREATE DATABASE "assert-migrator-reversible--4";
+  CREATE DATABASE "assert-migrator-reversible--5";
+  CREATE DATABASE "assert-migrator-reversible--6";
+  CREATE DATABASE "assert-migrator-reversible--7";
+  CREATE DATABASE "assert-migrator-reversible--8";
+  CREATE DATABASE "assert-migrator-reversible--9";
+  CREATE DATABASE "assert-migrator-reversible--10";
+  CREATE DATABASE "assert-migrator-reversible--11";
+  CREATE DATABASE "assert-migrator-reversible--12";
+  CREATE DATABASE "assert-migrator-reversible--13";
+  CREATE DATABASE "assert-migrator-reversible--14";
+  CREATE DATABASE "assert-migrator-reversible--15";
+  CREATE DATABASE "assert-migrator-reversible--16";
+  CREATE DATABASE "assert-migrator-reversible--17";
+  CREATE DATABASE "assert-migrator-reversible--18";
+  CREATE DATABASE "assert-migrator-reversible--19";
+  CREATE DATABASE "assert-migrator-reversible--20";
+  CREATE DATABASE "assert-migrator-reversible--21";
+  CREATE DATABASE "assert-migrator-reversible--22";
+  CREATE DATABASE "assert-migrator-reversible--23";
+  CREATE DATABASE "assert-migrator-reversible--24";
+  CREATE DATABASE "assert-migrator-reversible--25";
+  CREATE DATABASE "assert-migrator-reversible--26";
+  CREATE DATABASE "assert-migrator-reversible--27";
+  CREATE DATABASE "assert-migrator-reversible--28";
+  CREATE DATABASE "assert-migrator-reversible--29";
+  CREATE DATABASE "assert-migrator-reversible--30";
+  CREATE DATABASE "assert-migrator-reversible--31";
+  CREATE DATABASE "assert-migrator-reversible--32";
+  CREATE DATABASE "assert-migrator-reversible--33";
 EOM
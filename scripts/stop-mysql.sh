@@ -0,0 +1,3 @@
+#!/bin/sh
+
+docker kill assert-migrator-reversible__mysql
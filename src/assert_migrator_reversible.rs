@@ -1,4 +1,9 @@
 use ::sea_orm_migration::prelude::MigratorTrait;
+use ::sea_orm_migration::sea_orm::DatabaseConnection;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::TransactionTrait;
+use ::sea_orm_migration::MigrationTrait;
+use ::sea_orm_migration::SchemaManager;
 
 #[cfg(feature = "tokio")]
 use ::tokio::runtime::Builder;
@@ -6,12 +11,18 @@ use ::tokio::runtime::Builder;
 use ::tokio::runtime::Runtime;
 
 use crate::build_db_connection;
+use crate::diff_schema_snapshots;
+use crate::reversibility_options::CheckStart;
+use crate::CheckMode;
 use crate::DbConnection;
+use crate::NonReversibleKind;
+use crate::ReversibilityError;
+use crate::ReversibilityOptions;
+use crate::ReversibilityReport;
+use crate::SnapshotStage;
 
-use crate::queries::get_table_schemas;
-use crate::queries::get_type_schemas;
-use crate::queries::TableSchema;
-use crate::queries::TypeSchema;
+use crate::queries::get_schema_snapshot;
+use crate::queries::SchemaSnapshot;
 
 ///
 /// Runs a given `Migrator` against a new database.
@@ -37,9 +48,45 @@ pub async fn assert_migrator_reversible_async<'a, M>(migrator: M, db_conn: Optio
 where
     M: MigratorTrait,
 {
-    let maybe_index = find_index_of_non_reversible_migration_async(migrator, db_conn).await;
-    if let Some(index) = maybe_index {
-        panic!("Migration at index {} is not reversible", index);
+    assert_migrator_reversible_with_options_async(
+        migrator,
+        db_conn,
+        ReversibilityOptions::default(),
+    )
+    .await
+}
+
+///
+/// This is a version of `assert_migrator_reversible`,
+/// which takes options for changing what is checked.
+///
+#[cfg(feature = "tokio")]
+pub fn assert_migrator_reversible_with_options<'a, M>(
+    migrator: M,
+    db_conn: Option<DbConnection<'a>>,
+    options: ReversibilityOptions,
+) where
+    M: MigratorTrait,
+{
+    build_tokio_runtime().block_on(async move {
+        assert_migrator_reversible_with_options_async(migrator, db_conn, options).await
+    });
+}
+
+///
+/// This is an `async` version of `assert_migrator_reversible_with_options`.
+///
+pub async fn assert_migrator_reversible_with_options_async<'a, M>(
+    migrator: M,
+    db_conn: Option<DbConnection<'a>>,
+    options: ReversibilityOptions,
+) where
+    M: MigratorTrait,
+{
+    let maybe_report =
+        find_non_reversible_migration_with_options_async(migrator, db_conn, options).await;
+    if let Some(report) = maybe_report {
+        panic!("{}", report);
     }
 }
 
@@ -51,6 +98,77 @@ where
 ///
 /// Note for performance reasons, this will check migrations in reverse order.
 ///
+#[cfg(feature = "tokio")]
+pub fn find_index_of_non_reversible_migration<'a, M>(
+    migrator: M,
+    db_conn: Option<DbConnection<'a>>,
+) -> Option<usize>
+where
+    M: MigratorTrait,
+{
+    build_tokio_runtime().block_on(async move {
+        find_index_of_non_reversible_migration_async(migrator, db_conn).await
+    })
+}
+
+///
+/// This is an `async` version of `find_index_of_non_reversible_migration`.
+///
+pub async fn find_index_of_non_reversible_migration_async<'a, M>(
+    migrator: M,
+    db_conn: Option<DbConnection<'a>>,
+) -> Option<usize>
+where
+    M: MigratorTrait,
+{
+    find_non_reversible_migration_async(migrator, db_conn)
+        .await
+        .map(|report| report.index)
+}
+
+///
+/// This is a version of `find_index_of_non_reversible_migration`,
+/// which takes options for changing what is checked.
+///
+#[cfg(feature = "tokio")]
+pub fn find_index_of_non_reversible_migration_with_options<'a, M>(
+    migrator: M,
+    db_conn: Option<DbConnection<'a>>,
+    options: ReversibilityOptions,
+) -> Option<usize>
+where
+    M: MigratorTrait,
+{
+    build_tokio_runtime().block_on(async move {
+        find_index_of_non_reversible_migration_with_options_async(migrator, db_conn, options).await
+    })
+}
+
+///
+/// This is an `async` version of `find_index_of_non_reversible_migration_with_options`.
+///
+pub async fn find_index_of_non_reversible_migration_with_options_async<'a, M>(
+    migrator: M,
+    db_conn: Option<DbConnection<'a>>,
+    options: ReversibilityOptions,
+) -> Option<usize>
+where
+    M: MigratorTrait,
+{
+    find_non_reversible_migration_with_options_async(migrator, db_conn, options)
+        .await
+        .map(|report| report.index)
+}
+
+///
+/// Returns a report on the first migration it can find, which is not
+/// reversible. The report includes the schema before and after the
+/// migration, and the differences found.
+///
+/// `None` is returned if they are all reversible.
+///
+/// Note for performance reasons, this will check migrations in reverse order.
+///
 /*
  * The plan is to use this in build tests.
  * So most of the time we should expect the test to pass.
@@ -63,78 +181,598 @@ where
  *
  */
 #[cfg(feature = "tokio")]
-pub fn find_index_of_non_reversible_migration<'a, M>(
+pub fn find_non_reversible_migration<'a, M>(
     migrator: M,
     db_conn: Option<DbConnection<'a>>,
-) -> Option<usize>
+) -> Option<ReversibilityReport>
+where
+    M: MigratorTrait,
+{
+    build_tokio_runtime()
+        .block_on(async move { find_non_reversible_migration_async(migrator, db_conn).await })
+}
+
+///
+/// This is an `async` version of `find_non_reversible_migration`.
+///
+pub async fn find_non_reversible_migration_async<'a, M>(
+    migrator: M,
+    db_conn: Option<DbConnection<'a>>,
+) -> Option<ReversibilityReport>
+where
+    M: MigratorTrait,
+{
+    find_non_reversible_migration_with_options_async(
+        migrator,
+        db_conn,
+        ReversibilityOptions::default(),
+    )
+    .await
+}
+
+///
+/// This is a version of `find_non_reversible_migration`,
+/// which takes options for changing what is checked.
+///
+#[cfg(feature = "tokio")]
+pub fn find_non_reversible_migration_with_options<'a, M>(
+    migrator: M,
+    db_conn: Option<DbConnection<'a>>,
+    options: ReversibilityOptions,
+) -> Option<ReversibilityReport>
 where
     M: MigratorTrait,
 {
     build_tokio_runtime().block_on(async move {
-        find_index_of_non_reversible_migration_async(migrator, db_conn).await
+        find_non_reversible_migration_with_options_async(migrator, db_conn, options).await
     })
 }
 
 ///
-/// This is an `async` version of `find_index_of_non_reversible_migration`.
+/// This is an `async` version of `find_non_reversible_migration_with_options`.
 ///
-pub async fn find_index_of_non_reversible_migration_async<'a, M>(
+pub async fn find_non_reversible_migration_with_options_async<'a, M>(
+    migrator: M,
+    db_conn: Option<DbConnection<'a>>,
+    options: ReversibilityOptions,
+) -> Option<ReversibilityReport>
+where
+    M: MigratorTrait,
+{
+    try_find_non_reversible_migration_with_options_async(migrator, db_conn, options)
+        .await
+        .unwrap_or_else(|err| panic!("{}", err))
+}
+
+///
+/// This is the same as `find_non_reversible_migration`,
+/// except it returns an error, rather than panicking, when the database
+/// cannot be connected to, migrations fail to run, or the schema cannot be read.
+///
+#[cfg(feature = "tokio")]
+pub fn try_find_non_reversible_migration<'a, M>(
+    migrator: M,
+    db_conn: Option<DbConnection<'a>>,
+) -> Result<Option<ReversibilityReport>, ReversibilityError>
+where
+    M: MigratorTrait,
+{
+    build_tokio_runtime()
+        .block_on(async move { try_find_non_reversible_migration_async(migrator, db_conn).await })
+}
+
+///
+/// This is an `async` version of `try_find_non_reversible_migration`.
+///
+pub async fn try_find_non_reversible_migration_async<'a, M>(
+    migrator: M,
+    db_conn: Option<DbConnection<'a>>,
+) -> Result<Option<ReversibilityReport>, ReversibilityError>
+where
+    M: MigratorTrait,
+{
+    try_find_non_reversible_migration_with_options_async(
+        migrator,
+        db_conn,
+        ReversibilityOptions::default(),
+    )
+    .await
+}
+
+///
+/// This is a version of `try_find_non_reversible_migration`,
+/// which takes options for changing what is checked.
+///
+#[cfg(feature = "tokio")]
+pub fn try_find_non_reversible_migration_with_options<'a, M>(
+    migrator: M,
+    db_conn: Option<DbConnection<'a>>,
+    options: ReversibilityOptions,
+) -> Result<Option<ReversibilityReport>, ReversibilityError>
+where
+    M: MigratorTrait,
+{
+    build_tokio_runtime().block_on(async move {
+        try_find_non_reversible_migration_with_options_async(migrator, db_conn, options).await
+    })
+}
+
+///
+/// This is an `async` version of `try_find_non_reversible_migration_with_options`.
+///
+pub async fn try_find_non_reversible_migration_with_options_async<'a, M>(
     _migrator: M,
     db_conn: Option<DbConnection<'a>>,
-) -> Option<usize>
+    options: ReversibilityOptions,
+) -> Result<Option<ReversibilityReport>, ReversibilityError>
 where
     M: MigratorTrait,
 {
-    let db_connection = build_db_connection(db_conn).await;
-    let num_migrations = M::migrations().len();
-    let mut migration_step_schemas: Vec<Vec<TableSchema>> = Vec::with_capacity(num_migrations);
-    let mut migration_type_schemas: Vec<Vec<TypeSchema>> = Vec::with_capacity(num_migrations);
+    let db_connection = build_db_connection(db_conn)
+        .await
+        .map_err(ReversibilityError::Connection)?;
+
+    match options.check_mode {
+        CheckMode::UpThenDown => find_up_then_down::<M>(&db_connection, &options).await,
+        CheckMode::RoundTrip => find_round_trip::<M>(&db_connection, &options).await,
+    }
+}
+
+/*
+ * Migrations up to the last irreversible one are checked one at a time,
+ * with the round trip. As the migrations before an irreversible migration
+ * cannot be compared against a schema it has changed.
+ *
+ * Migrations following one with fixtures are also checked with the round trip.
+ * So the fixtures are verified straight after it goes down, before any later
+ * migration is run.
+ */
+async fn find_up_then_down<M>(
+    db_connection: &DatabaseConnection,
+    options: &ReversibilityOptions,
+) -> Result<Option<ReversibilityReport>, ReversibilityError>
+where
+    M: MigratorTrait,
+{
+    let migrations = M::migrations();
+    let num_migrations = migrations.len();
+    let start_index = find_start_index(&migrations, options)?;
+    check_irreversible_migrations_exist(&migrations, options)?;
+    let up_then_down_index = migrations
+        .iter()
+        .rposition(|migration| options.is_irreversible_migration(migration.name()))
+        .map(|index| (index + 1).max(start_index))
+        .unwrap_or(start_index);
+    let mut migration_snapshots: Vec<SchemaSnapshot> = Vec::with_capacity(num_migrations);
 
     // Go up all migrations.
-    for _ in 0..num_migrations {
-        let table_schemas = get_table_schemas(&db_connection).await;
-        migration_step_schemas.push(table_schemas);
+    for (index, migration) in migrations.iter().enumerate() {
+        let name = migration.name();
+        if index < start_index {
+            run_up::<M>(db_connection, index, name).await?;
+            seed_fixtures(db_connection, options, index, name).await?;
+            continue;
+        }
+
+        if index >= up_then_down_index {
+            let snapshot =
+                take_snapshot(db_connection, options, index, name, SnapshotStage::BeforeUp).await?;
+            migration_snapshots.push(snapshot);
+        }
+
+        if index < up_then_down_index || has_fixtures_to_verify(options, index) {
+            let maybe_report =
+                check_round_trip::<M>(db_connection, options, index, migration.as_ref()).await?;
+            if maybe_report.is_some() {
+                return Ok(maybe_report);
+            }
+            continue;
+        }
+
+        run_up::<M>(db_connection, index, name).await?;
+        seed_fixtures(db_connection, options, index, name).await?;
+    }
+
+    // Go down all migrations not yet checked.
+    for index in (up_then_down_index..num_migrations).rev() {
+        let name = migrations[index].name();
+        run_down::<M>(db_connection, index, name).await?;
 
-        let type_schemas = get_type_schemas(&db_connection).await;
-        migration_type_schemas.push(type_schemas);
+        let after = take_snapshot(
+            db_connection,
+            options,
+            index,
+            name,
+            SnapshotStage::AfterDown,
+        )
+        .await?;
+        let before = migration_snapshots
+            .pop()
+            .expect("expect up schema snapshot should exist");
+
+        if before != after {
+            let differences = diff_schema_snapshots(&before, &after);
+
+            return Ok(Some(ReversibilityReport {
+                index,
+                name: name.to_string(),
+                kind: NonReversibleKind::DownDiffers,
+                before,
+                after,
+                differences,
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+async fn find_round_trip<M>(
+    db_connection: &DatabaseConnection,
+    options: &ReversibilityOptions,
+) -> Result<Option<ReversibilityReport>, ReversibilityError>
+where
+    M: MigratorTrait,
+{
+    let migrations = M::migrations();
+    let start_index = find_start_index(&migrations, options)?;
+    check_irreversible_migrations_exist(&migrations, options)?;
+
+    for (index, migration) in migrations.iter().enumerate() {
+        if index < start_index {
+            run_up::<M>(db_connection, index, migration.name()).await?;
+            seed_fixtures(db_connection, options, index, migration.name()).await?;
+            continue;
+        }
+
+        let maybe_report =
+            check_round_trip::<M>(db_connection, options, index, migration.as_ref()).await?;
+        if maybe_report.is_some() {
+            return Ok(maybe_report);
+        }
+    }
+
+    Ok(None)
+}
+
+/*
+ * The migration is run up, down, and up again.
+ * This is slower, but also catches a `down` which leaves behind something
+ * that stops `up` from working a second time.
+ *
+ * The migration is left run up, with it's fixtures seeded.
+ */
+async fn check_round_trip<M>(
+    db_connection: &DatabaseConnection,
+    options: &ReversibilityOptions,
+    index: usize,
+    migration: &dyn MigrationTrait,
+) -> Result<Option<ReversibilityReport>, ReversibilityError>
+where
+    M: MigratorTrait,
+{
+    let name = migration.name();
+
+    let before =
+        take_snapshot(db_connection, options, index, name, SnapshotStage::BeforeUp).await?;
+    run_up::<M>(db_connection, index, name).await?;
+    let first_up =
+        take_snapshot(db_connection, options, index, name, SnapshotStage::AfterUp).await?;
+
+    if options.is_irreversible_migration(name) {
+        let maybe_report =
+            check_irreversible(db_connection, options, index, migration, before, first_up).await?;
+        if maybe_report.is_none() {
+            seed_fixtures(db_connection, options, index, name).await?;
+        }
+
+        return Ok(maybe_report);
+    }
+
+    run_down::<M>(db_connection, index, name).await?;
+    let after = take_snapshot(
+        db_connection,
+        options,
+        index,
+        name,
+        SnapshotStage::AfterDown,
+    )
+    .await?;
+
+    if before != after {
+        let differences = diff_schema_snapshots(&before, &after);
+
+        return Ok(Some(ReversibilityReport {
+            index,
+            name: name.to_string(),
+            kind: NonReversibleKind::DownDiffers,
+            before,
+            after,
+            differences,
+        }));
+    }
+
+    if let Some(message) = verify_fixtures(db_connection, options, index).await {
+        return Ok(Some(ReversibilityReport {
+            index,
+            name: name.to_string(),
+            kind: NonReversibleKind::DataLost(message),
+            before,
+            after,
+            differences: Vec::new(),
+        }));
+    }
 
-        <M as MigratorTrait>::up(&db_connection, Some(1))
+    if let Err(err) = <M as MigratorTrait>::up(db_connection, Some(1)).await {
+        return Ok(Some(ReversibilityReport {
+            index,
+            name: name.to_string(),
+            kind: NonReversibleKind::UpFailedAfterDown(err.to_string()),
+            before,
+            after,
+            differences: Vec::new(),
+        }));
+    }
+
+    let second_up = take_snapshot(
+        db_connection,
+        options,
+        index,
+        name,
+        SnapshotStage::AfterUpAgain,
+    )
+    .await?;
+    if first_up != second_up {
+        let differences = diff_schema_snapshots(&first_up, &second_up);
+
+        return Ok(Some(ReversibilityReport {
+            index,
+            name: name.to_string(),
+            kind: NonReversibleKind::UpDiffersAfterDown,
+            before: first_up,
+            after: second_up,
+            differences,
+        }));
+    }
+
+    seed_fixtures(db_connection, options, index, name).await?;
+
+    Ok(None)
+}
+
+///
+/// Runs a migration marked as irreversible down, which has already been run up.
+/// A report is returned if it turns out to be reversible.
+///
+/// The migration is run directly, rather than through the `Migrator`,
+/// so it stays recorded as run up. If `down` changed anything, `up` is run
+/// again. Failures of either are expected, and ignored.
+///
+async fn check_irreversible(
+    db_connection: &DatabaseConnection,
+    options: &ReversibilityOptions,
+    index: usize,
+    migration: &dyn MigrationTrait,
+    before: SchemaSnapshot,
+    first_up: SchemaSnapshot,
+) -> Result<Option<ReversibilityReport>, ReversibilityError> {
+    let name = migration.name();
+
+    if run_migration_directly(db_connection, migration, Direction::Down)
+        .await
+        .is_err()
+    {
+        return Ok(None);
+    }
+
+    let after = take_snapshot(
+        db_connection,
+        options,
+        index,
+        name,
+        SnapshotStage::AfterDown,
+    )
+    .await?;
+
+    // When `up` changes nothing, there is nothing to tell it apart from being reversible.
+    let is_reversible = before != first_up
+        && before == after
+        && verify_fixtures(db_connection, options, index)
             .await
-            .expect("expect migration up should succeed");
+            .is_none();
+    if is_reversible {
+        return Ok(Some(ReversibilityReport {
+            index,
+            name: name.to_string(),
+            kind: NonReversibleKind::MarkedIrreversible,
+            before,
+            after,
+            differences: Vec::new(),
+        }));
     }
 
-    // Go down all migrations.
-    for i in 0..num_migrations {
-        <M as MigratorTrait>::down(&db_connection, Some(1))
+    if after != first_up {
+        run_migration_directly(db_connection, migration, Direction::Up)
             .await
-            .expect("expect migration down should succeed");
+            .map_err(|source| ReversibilityError::Up {
+                index,
+                name: name.to_string(),
+                source,
+            })?;
+    }
 
-        // Compare table schema changes
-        let down_table_schemas = get_table_schemas(&db_connection).await;
-        let up_table_schemas = migration_step_schemas
-            .pop()
-            .expect("expect up table schemas should exist");
-        if down_table_schemas != up_table_schemas {
-            for i in 0..up_table_schemas.len() {
-                let left = &up_table_schemas[i];
-                let right = &down_table_schemas[i];
-
-                if left != right {
-                    println!("{:#?}", left);
-                    println!("{:#?}", right);
-                    return Some(num_migrations - i - 1);
-                }
-            }
-            return Some(num_migrations - i - 1);
+    Ok(None)
+}
+
+enum Direction {
+    Up,
+    Down,
+}
+
+///
+/// Runs a single migration within a transaction.
+/// This does not record it as run up or down.
+///
+async fn run_migration_directly(
+    db_connection: &DatabaseConnection,
+    migration: &dyn MigrationTrait,
+    direction: Direction,
+) -> Result<(), DbErr> {
+    let transaction = db_connection.begin().await?;
+    let manager = SchemaManager::new(&transaction);
+    let result = match direction {
+        Direction::Up => migration.up(&manager).await,
+        Direction::Down => migration.down(&manager).await,
+    };
+
+    match result {
+        Ok(()) => transaction.commit().await,
+        Err(err) => {
+            transaction.rollback().await?;
+            Err(err)
         }
+    }
+}
 
-        // Compare type schema changes
-        let down_type_schemas = get_type_schemas(&db_connection).await;
-        let up_type_schemas = migration_type_schemas
-            .pop()
-            .expect("expect up table schemas should exist");
-        if down_type_schemas != up_type_schemas {
-            return Some(num_migrations - i - 1);
+///
+/// The index of the first migration to check.
+/// Migrations before this are only run up.
+///
+fn find_start_index(
+    migrations: &[Box<dyn MigrationTrait>],
+    options: &ReversibilityOptions,
+) -> Result<usize, ReversibilityError> {
+    let num_migrations = migrations.len();
+
+    match &options.start {
+        CheckStart::First => Ok(0),
+        CheckStart::Index(index) => Ok((*index).min(num_migrations)),
+        CheckStart::Name(name) => migrations
+            .iter()
+            .position(|migration| migration.name() == name)
+            .ok_or_else(|| ReversibilityError::UnknownMigration(name.clone())),
+        CheckStart::Last(count) => Ok(num_migrations.saturating_sub(*count)),
+    }
+}
+
+///
+/// Every name given to `ReversibilityOptions::with_irreversible_migrations`
+/// must match a migration. Otherwise a typo would quietly report it as not reversible.
+///
+fn check_irreversible_migrations_exist(
+    migrations: &[Box<dyn MigrationTrait>],
+    options: &ReversibilityOptions,
+) -> Result<(), ReversibilityError> {
+    let maybe_unknown_name = options.irreversible_migrations.iter().find(|name| {
+        !migrations
+            .iter()
+            .any(|migration| migration.name() == name.as_str())
+    });
+
+    match maybe_unknown_name {
+        Some(name) => Err(ReversibilityError::UnknownMigration(name.clone())),
+        None => Ok(()),
+    }
+}
+
+async fn take_snapshot(
+    db_connection: &DatabaseConnection,
+    options: &ReversibilityOptions,
+    index: usize,
+    name: &str,
+    stage: SnapshotStage,
+) -> Result<SchemaSnapshot, ReversibilityError> {
+    get_schema_snapshot(db_connection, options)
+        .await
+        .map_err(|source| ReversibilityError::Introspection {
+            index,
+            name: name.to_string(),
+            stage,
+            source,
+        })
+}
+
+async fn run_up<M>(
+    db_connection: &DatabaseConnection,
+    index: usize,
+    name: &str,
+) -> Result<(), ReversibilityError>
+where
+    M: MigratorTrait,
+{
+    <M as MigratorTrait>::up(db_connection, Some(1))
+        .await
+        .map_err(|source| ReversibilityError::Up {
+            index,
+            name: name.to_string(),
+            source,
+        })
+}
+
+async fn run_down<M>(
+    db_connection: &DatabaseConnection,
+    index: usize,
+    name: &str,
+) -> Result<(), ReversibilityError>
+where
+    M: MigratorTrait,
+{
+    <M as MigratorTrait>::down(db_connection, Some(1))
+        .await
+        .map_err(|source| ReversibilityError::Down {
+            index,
+            name: name.to_string(),
+            source,
+        })
+}
+
+async fn seed_fixtures(
+    db_connection: &DatabaseConnection,
+    options: &ReversibilityOptions,
+    migration_index: usize,
+    name: &str,
+) -> Result<(), ReversibilityError> {
+    for registered in &options.fixtures {
+        if registered.migration_index == migration_index {
+            registered
+                .fixture
+                .seed(db_connection)
+                .await
+                .map_err(|source| ReversibilityError::Fixture {
+                    index: migration_index,
+                    name: name.to_string(),
+                    source,
+                })?;
+        }
+    }
+
+    Ok(())
+}
+
+///
+/// Returns true if fixtures are seeded for the migration before `migration_index`.
+///
+fn has_fixtures_to_verify(options: &ReversibilityOptions, migration_index: usize) -> bool {
+    options
+        .fixtures
+        .iter()
+        .any(|registered| registered.migration_index + 1 == migration_index)
+}
+
+///
+/// Verifies the fixtures seeded before the migration at `migration_index`
+/// was run up. Returns the message of the first to fail.
+///
+async fn verify_fixtures(
+    db_connection: &DatabaseConnection,
+    options: &ReversibilityOptions,
+    migration_index: usize,
+) -> Option<String> {
+    for registered in &options.fixtures {
+        if registered.migration_index + 1 == migration_index {
+            if let Err(message) = registered.fixture.verify(db_connection).await {
+                return Some(message);
+            }
         }
     }
 
@@ -1,8 +1,9 @@
 use crate::queries::new_test_db_connection;
 
 use ::sea_orm_migration::sea_orm::DatabaseConnection;
+use ::sea_orm_migration::sea_orm::DbErr;
 
-static TEST_DATABASE_URL: &str = &"sqlite::memory:";
+static TEST_DATABASE_URL: &str = "sqlite::memory:";
 
 pub enum DbConnection<'a> {
     Url(&'a str),
@@ -11,9 +12,9 @@ pub enum DbConnection<'a> {
 
 pub(crate) async fn build_db_connection<'a>(
     db_conn: Option<DbConnection<'a>>,
-) -> DatabaseConnection {
+) -> Result<DatabaseConnection, DbErr> {
     match db_conn {
-        Some(DbConnection::DatabaseConnection(db_connection)) => db_connection,
+        Some(DbConnection::DatabaseConnection(db_connection)) => Ok(db_connection),
         Some(DbConnection::Url(db_url)) => new_test_db_connection(db_url).await,
         None => new_test_db_connection(TEST_DATABASE_URL).await,
     }
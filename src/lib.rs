@@ -1,7 +1,39 @@
 mod queries;
+pub use crate::queries::ColumnSchema;
+pub use crate::queries::ConstraintKind;
+pub use crate::queries::ConstraintSchema;
+pub use crate::queries::ExtensionSchema;
+pub use crate::queries::FunctionSchema;
+pub use crate::queries::IndexSchema;
+pub use crate::queries::NamespaceSchema;
+pub use crate::queries::PolicySchema;
+pub use crate::queries::PrivilegeSchema;
+pub use crate::queries::RowSecuritySchema;
+pub use crate::queries::SchemaSnapshot;
+pub use crate::queries::SequenceSchema;
+pub use crate::queries::TableSchema;
+pub use crate::queries::TriggerSchema;
+pub use crate::queries::TypeKind;
+pub use crate::queries::TypeSchema;
+pub use crate::queries::ViewSchema;
 
 mod assert_migrator_reversible;
 pub use self::assert_migrator_reversible::*;
 
 mod db_connection;
 pub use self::db_connection::*;
+
+mod migration_fixture;
+pub use self::migration_fixture::*;
+
+mod reversibility_options;
+pub use self::reversibility_options::*;
+
+mod reversibility_error;
+pub use self::reversibility_error::*;
+
+mod reversibility_report;
+pub use self::reversibility_report::*;
+
+mod schema_difference;
+pub use self::schema_difference::*;
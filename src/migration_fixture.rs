@@ -0,0 +1,47 @@
+use ::sea_orm_migration::prelude::async_trait;
+use ::sea_orm_migration::sea_orm::DatabaseConnection;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::std::fmt::Debug;
+use ::std::fmt::Formatter;
+use ::std::fmt::Result as FmtResult;
+use ::std::sync::Arc;
+
+///
+/// Data inserted after a migration is run up, and checked again after the
+/// next migration has been run up and back down.
+///
+/// This is for finding `down` migrations which restore the schema, but lose data.
+/// i.e. dropping and adding a column back, instead of renaming it.
+///
+/// Fixtures are added with `ReversibilityOptions::with_fixture`.
+///
+#[async_trait::async_trait]
+pub trait MigrationFixture: Send + Sync {
+    ///
+    /// Inserts the data. This is run once, after the migration it is added
+    /// for has been run up.
+    ///
+    async fn seed(&self, db_connection: &DatabaseConnection) -> Result<(), DbErr>;
+
+    ///
+    /// Checks the data is still present and unchanged.
+    /// This is run after the next migration has been run up and back down.
+    ///
+    /// Returns a message describing what was lost, if it is not.
+    ///
+    async fn verify(&self, db_connection: &DatabaseConnection) -> Result<(), String>;
+}
+
+#[derive(Clone)]
+pub(crate) struct RegisteredFixture {
+    pub(crate) migration_index: usize,
+    pub(crate) fixture: Arc<dyn MigrationFixture>,
+}
+
+impl Debug for RegisteredFixture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("RegisteredFixture")
+            .field("migration_index", &self.migration_index)
+            .finish_non_exhaustive()
+    }
+}
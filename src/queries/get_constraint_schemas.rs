@@ -0,0 +1,87 @@
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+use ::sea_orm_migration::sea_orm::Statement;
+
+mod mysql;
+mod postgres;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum ConstraintKind {
+    PrimaryKey,
+    ForeignKey,
+    Unique,
+    Check,
+    Exclusion,
+}
+
+///
+/// A constraint on a table.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct ConstraintSchema {
+    pub schema: String,
+    pub table_name: String,
+    pub name: String,
+    pub kind: ConstraintKind,
+
+    /// The columns constrained. In constraint order.
+    pub columns: Vec<String>,
+
+    /// For foreign keys, the table being referenced.
+    pub referenced_table: Option<String>,
+
+    /// For foreign keys, the columns being referenced.
+    pub referenced_columns: Vec<String>,
+
+    /// The `ON UPDATE` action, i.e. `CASCADE`.
+    pub on_update: Option<String>,
+
+    /// The `ON DELETE` action, i.e. `CASCADE`.
+    pub on_delete: Option<String>,
+
+    pub is_deferrable: bool,
+    pub is_initially_deferred: bool,
+
+    /// For check and exclusion constraints, the constraint definition.
+    /// i.e. `CHECK (price > 0)`.
+    pub definition: Option<String>,
+}
+
+pub async fn get_constraint_schemas<C>(db_connection: &C) -> Result<Vec<ConstraintSchema>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = db_connection.get_database_backend();
+    let Some(sql) = get_constraint_query_sql(db_backend) else {
+        return Ok(Vec::new());
+    };
+    let list_constraints_statement = Statement::from_string(db_backend, sql.to_string());
+
+    let constraint_results = db_connection.query_all(list_constraints_statement).await?;
+
+    let mut constraint_schemas = build_constraint_schema(db_backend, constraint_results)?;
+    constraint_schemas.sort();
+
+    Ok(constraint_schemas)
+}
+
+fn build_constraint_schema(
+    db_backend: DatabaseBackend,
+    constraint_results: Vec<QueryResult>,
+) -> Result<Vec<ConstraintSchema>, DbErr> {
+    match db_backend {
+        DatabaseBackend::MySql => mysql::build_constraint_schema(constraint_results),
+        DatabaseBackend::Postgres => postgres::build_constraint_schema(constraint_results),
+        DatabaseBackend::Sqlite => Ok(Vec::new()),
+    }
+}
+
+fn get_constraint_query_sql(db_backend: DatabaseBackend) -> Option<&'static str> {
+    match db_backend {
+        DatabaseBackend::MySql => Some(mysql::QUERY_CONSTRAINT_SCHEMA_SQL),
+        DatabaseBackend::Postgres => Some(postgres::QUERY_CONSTRAINT_SCHEMA_SQL),
+        DatabaseBackend::Sqlite => None,
+    }
+}
@@ -0,0 +1,75 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::ConstraintKind;
+use super::ConstraintSchema;
+
+/*
+ * Only foreign keys are listed. Primary keys and unique constraints are
+ * found as indexes.
+ *
+ * Only the current database is read, so the schema is left empty.
+ */
+pub const QUERY_CONSTRAINT_SCHEMA_SQL: &str = r#"
+    SELECT
+        CAST(k.TABLE_NAME AS CHAR) AS table_name,
+        CAST(k.CONSTRAINT_NAME AS CHAR) AS constraint_name,
+        CAST(k.COLUMN_NAME AS CHAR) AS column_name,
+        CAST(k.REFERENCED_TABLE_NAME AS CHAR) AS referenced_table,
+        CAST(k.REFERENCED_COLUMN_NAME AS CHAR) AS referenced_column,
+        CAST(r.UPDATE_RULE AS CHAR) AS on_update,
+        CAST(r.DELETE_RULE AS CHAR) AS on_delete
+    FROM information_schema.KEY_COLUMN_USAGE k
+    JOIN information_schema.REFERENTIAL_CONSTRAINTS r
+        ON r.CONSTRAINT_SCHEMA = k.CONSTRAINT_SCHEMA
+        AND r.TABLE_NAME = k.TABLE_NAME
+        AND r.CONSTRAINT_NAME = k.CONSTRAINT_NAME
+    WHERE k.TABLE_SCHEMA = DATABASE()
+    ORDER BY k.TABLE_NAME, k.CONSTRAINT_NAME, k.ORDINAL_POSITION
+"#;
+
+pub fn build_constraint_schema(
+    constraint_results: Vec<QueryResult>,
+) -> Result<Vec<ConstraintSchema>, DbErr> {
+    let mut all_constraint_schemas: Vec<ConstraintSchema> = Vec::new();
+
+    for constraint_result in constraint_results {
+        let table_name = constraint_result.try_get::<String>("", "table_name")?;
+        let name = constraint_result.try_get::<String>("", "constraint_name")?;
+        let column = constraint_result.try_get::<String>("", "column_name")?;
+        let referenced_column = constraint_result.try_get::<String>("", "referenced_column")?;
+
+        let is_new_constraint = !matches!(
+            all_constraint_schemas.last(),
+            Some(constraint_schema) if constraint_schema.table_name == table_name && constraint_schema.name == name
+        );
+        if is_new_constraint {
+            let referenced_table = constraint_result.try_get::<String>("", "referenced_table")?;
+            let on_update = constraint_result.try_get::<String>("", "on_update")?;
+            let on_delete = constraint_result.try_get::<String>("", "on_delete")?;
+
+            all_constraint_schemas.push(ConstraintSchema {
+                schema: String::new(),
+                table_name,
+                name,
+                kind: ConstraintKind::ForeignKey,
+                columns: Vec::new(),
+                referenced_table: Some(referenced_table),
+                referenced_columns: Vec::new(),
+                on_update: Some(on_update),
+                on_delete: Some(on_delete),
+                is_deferrable: false,
+                is_initially_deferred: false,
+                definition: None,
+            });
+        }
+
+        let constraint_schema = all_constraint_schemas
+            .last_mut()
+            .expect("expect a constraint schema to be present");
+        constraint_schema.columns.push(column);
+        constraint_schema.referenced_columns.push(referenced_column);
+    }
+
+    Ok(all_constraint_schemas)
+}
@@ -0,0 +1,122 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::ConstraintKind;
+use super::ConstraintSchema;
+
+/*
+ * Constraints with a `conrelid` of 0 are on domains, rather than tables.
+ */
+pub const QUERY_CONSTRAINT_SCHEMA_SQL: &str = r#"
+    SELECT
+        n.nspname AS schema_name,
+        t.relname AS table_name,
+        con.conname AS constraint_name,
+        con.contype::text AS constraint_type,
+        ARRAY(
+            SELECT a.attname::text
+            FROM unnest(con.conkey) WITH ORDINALITY AS k(attnum, ord)
+            JOIN pg_catalog.pg_attribute a
+                ON a.attrelid = con.conrelid AND a.attnum = k.attnum
+            ORDER BY k.ord
+        ) AS columns,
+        rt.relname AS referenced_table,
+        ARRAY(
+            SELECT a.attname::text
+            FROM unnest(con.confkey) WITH ORDINALITY AS k(attnum, ord)
+            JOIN pg_catalog.pg_attribute a
+                ON a.attrelid = con.confrelid AND a.attnum = k.attnum
+            ORDER BY k.ord
+        ) AS referenced_columns,
+        CASE con.confupdtype
+            WHEN 'a' THEN 'NO ACTION'
+            WHEN 'r' THEN 'RESTRICT'
+            WHEN 'c' THEN 'CASCADE'
+            WHEN 'n' THEN 'SET NULL'
+            WHEN 'd' THEN 'SET DEFAULT'
+        END AS on_update,
+        CASE con.confdeltype
+            WHEN 'a' THEN 'NO ACTION'
+            WHEN 'r' THEN 'RESTRICT'
+            WHEN 'c' THEN 'CASCADE'
+            WHEN 'n' THEN 'SET NULL'
+            WHEN 'd' THEN 'SET DEFAULT'
+        END AS on_delete,
+        con.condeferrable AS is_deferrable,
+        con.condeferred AS is_initially_deferred,
+        CASE WHEN con.contype IN ('c', 'x')
+            THEN pg_catalog.pg_get_constraintdef(con.oid, true)
+        END AS definition
+    FROM pg_catalog.pg_constraint con
+    JOIN pg_catalog.pg_class t ON t.oid = con.conrelid
+    JOIN pg_catalog.pg_namespace n ON n.oid = t.relnamespace
+    LEFT JOIN pg_catalog.pg_class rt ON rt.oid = con.confrelid
+    WHERE con.conrelid != 0
+    AND con.contype IN ('p', 'f', 'u', 'c', 'x')
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND t.relname != 'seaql_migrations'
+    AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_depend ext
+        WHERE ext.classid = 'pg_catalog.pg_class'::regclass
+        AND ext.objid = t.oid
+        AND ext.deptype = 'e'
+    )
+    ORDER BY n.nspname, t.relname, con.conname
+"#;
+
+pub fn build_constraint_schema(
+    constraint_results: Vec<QueryResult>,
+) -> Result<Vec<ConstraintSchema>, DbErr> {
+    constraint_results
+        .into_iter()
+        .map(|constraint_result| {
+            let schema = constraint_result.try_get::<String>("", "schema_name")?;
+            let table_name = constraint_result.try_get::<String>("", "table_name")?;
+            let name = constraint_result.try_get::<String>("", "constraint_name")?;
+            let constraint_type = constraint_result.try_get::<String>("", "constraint_type")?;
+            let columns = constraint_result.try_get::<Vec<String>>("", "columns")?;
+            let referenced_table =
+                constraint_result.try_get::<Option<String>>("", "referenced_table")?;
+            let referenced_columns =
+                constraint_result.try_get::<Vec<String>>("", "referenced_columns")?;
+            let on_update = constraint_result.try_get::<Option<String>>("", "on_update")?;
+            let on_delete = constraint_result.try_get::<Option<String>>("", "on_delete")?;
+            let is_deferrable = constraint_result.try_get::<bool>("", "is_deferrable")?;
+            let is_initially_deferred =
+                constraint_result.try_get::<bool>("", "is_initially_deferred")?;
+            let definition = constraint_result.try_get::<Option<String>>("", "definition")?;
+
+            Ok(ConstraintSchema {
+                schema,
+                table_name,
+                name,
+                kind: to_constraint_kind(&constraint_type)?,
+                columns,
+                referenced_table,
+                referenced_columns,
+                on_update,
+                on_delete,
+                is_deferrable,
+                is_initially_deferred,
+                definition,
+            })
+        })
+        .collect()
+}
+
+fn to_constraint_kind(constraint_type: &str) -> Result<ConstraintKind, DbErr> {
+    let constraint_kind = match constraint_type {
+        "p" => ConstraintKind::PrimaryKey,
+        "f" => ConstraintKind::ForeignKey,
+        "u" => ConstraintKind::Unique,
+        "c" => ConstraintKind::Check,
+        "x" => ConstraintKind::Exclusion,
+        _ => {
+            return Err(DbErr::Custom(format!(
+                "unknown constraint type `{constraint_type}`"
+            )))
+        }
+    };
+
+    Ok(constraint_kind)
+}
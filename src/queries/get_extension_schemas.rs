@@ -0,0 +1,61 @@
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+use ::sea_orm_migration::sea_orm::Statement;
+
+mod postgres;
+
+///
+/// An extension, as installed with `CREATE EXTENSION`.
+///
+/// The tables, functions, and types an extension brings with it are not
+/// compared. Only the extension itself.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct ExtensionSchema {
+    pub name: String,
+
+    /// The installed version, i.e. `1.3`.
+    pub version: String,
+
+    /// The schema the extension was installed into.
+    pub schema: String,
+}
+
+pub async fn get_extension_schemas<C>(db_connection: &C) -> Result<Vec<ExtensionSchema>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = db_connection.get_database_backend();
+    let Some(sql) = get_extension_query_sql(db_backend) else {
+        return Ok(Vec::new());
+    };
+    let list_extensions_statement = Statement::from_string(db_backend, sql.to_string());
+
+    let extension_results = db_connection.query_all(list_extensions_statement).await?;
+
+    let mut extension_schemas = build_extension_schema(db_backend, extension_results)?;
+    extension_schemas.sort();
+
+    Ok(extension_schemas)
+}
+
+fn build_extension_schema(
+    db_backend: DatabaseBackend,
+    extension_results: Vec<QueryResult>,
+) -> Result<Vec<ExtensionSchema>, DbErr> {
+    match db_backend {
+        DatabaseBackend::MySql => Ok(Vec::new()),
+        DatabaseBackend::Postgres => postgres::build_extension_schema(extension_results),
+        DatabaseBackend::Sqlite => Ok(Vec::new()),
+    }
+}
+
+fn get_extension_query_sql(db_backend: DatabaseBackend) -> Option<&'static str> {
+    match db_backend {
+        DatabaseBackend::MySql => None,
+        DatabaseBackend::Postgres => Some(postgres::QUERY_EXTENSION_SCHEMA_SQL),
+        DatabaseBackend::Sqlite => None,
+    }
+}
@@ -0,0 +1,33 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::ExtensionSchema;
+
+pub const QUERY_EXTENSION_SCHEMA_SQL: &str = r#"
+    SELECT
+        e.extname AS extension_name,
+        e.extversion AS extension_version,
+        n.nspname AS schema_name
+    FROM pg_catalog.pg_extension e
+    JOIN pg_catalog.pg_namespace n ON n.oid = e.extnamespace
+    ORDER BY e.extname
+"#;
+
+pub fn build_extension_schema(
+    extension_results: Vec<QueryResult>,
+) -> Result<Vec<ExtensionSchema>, DbErr> {
+    extension_results
+        .into_iter()
+        .map(|extension_result| {
+            let name = extension_result.try_get::<String>("", "extension_name")?;
+            let version = extension_result.try_get::<String>("", "extension_version")?;
+            let schema = extension_result.try_get::<String>("", "schema_name")?;
+
+            Ok(ExtensionSchema {
+                name,
+                version,
+                schema,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,71 @@
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+use ::sea_orm_migration::sea_orm::Statement;
+
+mod postgres;
+
+///
+/// A user defined function, or procedure.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct FunctionSchema {
+    pub schema: String,
+    pub name: String,
+
+    /// The argument signature, which tells overloaded functions apart.
+    /// i.e. `name text, total integer`.
+    pub arguments: String,
+
+    /// This is `None` for procedures.
+    pub return_type: Option<String>,
+
+    pub is_procedure: bool,
+
+    /// i.e. `plpgsql`.
+    pub language: String,
+
+    /// An MD5 hash of the function body.
+    pub body_hash: String,
+
+    /// `IMMUTABLE`, `STABLE`, or `VOLATILE`.
+    pub volatility: String,
+}
+
+pub async fn get_function_schemas<C>(db_connection: &C) -> Result<Vec<FunctionSchema>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = db_connection.get_database_backend();
+    let Some(sql) = get_function_query_sql(db_backend) else {
+        return Ok(Vec::new());
+    };
+    let list_functions_statement = Statement::from_string(db_backend, sql.to_string());
+
+    let function_results = db_connection.query_all(list_functions_statement).await?;
+
+    let mut function_schemas = build_function_schema(db_backend, function_results)?;
+    function_schemas.sort();
+
+    Ok(function_schemas)
+}
+
+fn build_function_schema(
+    db_backend: DatabaseBackend,
+    function_results: Vec<QueryResult>,
+) -> Result<Vec<FunctionSchema>, DbErr> {
+    match db_backend {
+        DatabaseBackend::MySql => Ok(Vec::new()),
+        DatabaseBackend::Postgres => postgres::build_function_schema(function_results),
+        DatabaseBackend::Sqlite => Ok(Vec::new()),
+    }
+}
+
+fn get_function_query_sql(db_backend: DatabaseBackend) -> Option<&'static str> {
+    match db_backend {
+        DatabaseBackend::MySql => None,
+        DatabaseBackend::Postgres => Some(postgres::QUERY_FUNCTION_SCHEMA_SQL),
+        DatabaseBackend::Sqlite => None,
+    }
+}
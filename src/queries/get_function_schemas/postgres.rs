@@ -0,0 +1,65 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::FunctionSchema;
+
+/*
+ * Only plain functions ('f') and procedures ('p') are listed.
+ * Aggregates and window functions are skipped.
+ */
+pub const QUERY_FUNCTION_SCHEMA_SQL: &str = r#"
+    SELECT
+        n.nspname AS schema_name,
+        p.proname AS function_name,
+        pg_catalog.pg_get_function_identity_arguments(p.oid) AS arguments,
+        pg_catalog.pg_get_function_result(p.oid) AS return_type,
+        p.prokind = 'p' AS is_procedure,
+        l.lanname AS language,
+        md5(p.prosrc) AS body_hash,
+        CASE p.provolatile
+            WHEN 'i' THEN 'IMMUTABLE'
+            WHEN 's' THEN 'STABLE'
+            WHEN 'v' THEN 'VOLATILE'
+        END AS volatility
+    FROM pg_catalog.pg_proc p
+    JOIN pg_catalog.pg_namespace n ON n.oid = p.pronamespace
+    JOIN pg_catalog.pg_language l ON l.oid = p.prolang
+    WHERE p.prokind IN ('f', 'p')
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_depend ext
+        WHERE ext.classid = 'pg_catalog.pg_proc'::regclass
+        AND ext.objid = p.oid
+        AND ext.deptype = 'e'
+    )
+    ORDER BY n.nspname, p.proname
+"#;
+
+pub fn build_function_schema(
+    function_results: Vec<QueryResult>,
+) -> Result<Vec<FunctionSchema>, DbErr> {
+    function_results
+        .into_iter()
+        .map(|function_result| {
+            let schema = function_result.try_get::<String>("", "schema_name")?;
+            let name = function_result.try_get::<String>("", "function_name")?;
+            let arguments = function_result.try_get::<String>("", "arguments")?;
+            let return_type = function_result.try_get::<Option<String>>("", "return_type")?;
+            let is_procedure = function_result.try_get::<bool>("", "is_procedure")?;
+            let language = function_result.try_get::<String>("", "language")?;
+            let body_hash = function_result.try_get::<String>("", "body_hash")?;
+            let volatility = function_result.try_get::<String>("", "volatility")?;
+
+            Ok(FunctionSchema {
+                schema,
+                name,
+                arguments,
+                return_type,
+                is_procedure,
+                language,
+                body_hash,
+                volatility,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,70 @@
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+use ::sea_orm_migration::sea_orm::Statement;
+
+mod mysql;
+mod postgres;
+mod sqlite;
+
+///
+/// An index on a table.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct IndexSchema {
+    pub schema: String,
+    pub table_name: String,
+    pub name: String,
+
+    /// The columns, or expressions, indexed. In index order.
+    pub columns: Vec<String>,
+
+    /// Non-key columns stored in the index, from `INCLUDE (...)`.
+    pub include_columns: Vec<String>,
+
+    pub is_unique: bool,
+
+    /// The index method, i.e. `BTREE`.
+    pub method: Option<String>,
+
+    /// The `WHERE` clause, for partial indexes.
+    pub predicate: Option<String>,
+}
+
+pub async fn get_index_schemas<C>(db_connection: &C) -> Result<Vec<IndexSchema>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = db_connection.get_database_backend();
+    let Some(sql) = get_index_query_sql(db_backend) else {
+        return Ok(Vec::new());
+    };
+    let list_indexes_statement = Statement::from_string(db_backend, sql.to_string());
+
+    let index_results = db_connection.query_all(list_indexes_statement).await?;
+
+    let mut index_schemas = build_index_schema(db_backend, index_results)?;
+    index_schemas.sort();
+
+    Ok(index_schemas)
+}
+
+fn build_index_schema(
+    db_backend: DatabaseBackend,
+    index_results: Vec<QueryResult>,
+) -> Result<Vec<IndexSchema>, DbErr> {
+    match db_backend {
+        DatabaseBackend::MySql => mysql::build_index_schema(index_results),
+        DatabaseBackend::Postgres => postgres::build_index_schema(index_results),
+        DatabaseBackend::Sqlite => sqlite::build_index_schema(index_results),
+    }
+}
+
+fn get_index_query_sql(db_backend: DatabaseBackend) -> Option<&'static str> {
+    match db_backend {
+        DatabaseBackend::MySql => Some(mysql::QUERY_INDEX_SCHEMA_SQL),
+        DatabaseBackend::Postgres => Some(postgres::QUERY_INDEX_SCHEMA_SQL),
+        DatabaseBackend::Sqlite => Some(sqlite::QUERY_INDEX_SCHEMA_SQL),
+    }
+}
@@ -0,0 +1,66 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::IndexSchema;
+
+/*
+ * There is one row per indexed column, so these are grouped back up into
+ * their indexes.
+ *
+ * Only the current database is read, so the schema is left empty.
+ */
+pub const QUERY_INDEX_SCHEMA_SQL: &str = r#"
+    SELECT
+        CAST(TABLE_NAME AS CHAR) AS table_name,
+        CAST(INDEX_NAME AS CHAR) AS index_name,
+        CAST(
+            CONCAT(
+                COALESCE(COLUMN_NAME, '?'),
+                COALESCE(CONCAT('(', SUB_PART, ')'), '')
+            ) AS CHAR
+        ) AS column_name,
+        NON_UNIQUE = 0 AS is_unique,
+        CAST(INDEX_TYPE AS CHAR) AS index_method
+    FROM information_schema.STATISTICS
+    WHERE TABLE_SCHEMA = DATABASE()
+    AND TABLE_NAME != 'seaql_migrations'
+    ORDER BY TABLE_NAME, INDEX_NAME, SEQ_IN_INDEX
+"#;
+
+pub fn build_index_schema(index_results: Vec<QueryResult>) -> Result<Vec<IndexSchema>, DbErr> {
+    let mut all_index_schemas: Vec<IndexSchema> = Vec::new();
+
+    for index_result in index_results {
+        let table_name = index_result.try_get::<String>("", "table_name")?;
+        let name = index_result.try_get::<String>("", "index_name")?;
+        let column_name = index_result.try_get::<String>("", "column_name")?;
+
+        let is_new_index = !matches!(
+            all_index_schemas.last(),
+            Some(index_schema) if index_schema.table_name == table_name && index_schema.name == name
+        );
+        if is_new_index {
+            let is_unique = index_result.try_get::<bool>("", "is_unique")?;
+            let method = index_result.try_get::<Option<String>>("", "index_method")?;
+
+            all_index_schemas.push(IndexSchema {
+                schema: String::new(),
+                table_name,
+                name,
+                columns: Vec::new(),
+                include_columns: Vec::new(),
+                is_unique,
+                method,
+                predicate: None,
+            });
+        }
+
+        all_index_schemas
+            .last_mut()
+            .expect("expect an index schema to be present")
+            .columns
+            .push(column_name);
+    }
+
+    Ok(all_index_schemas)
+}
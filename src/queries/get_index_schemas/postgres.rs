@@ -0,0 +1,70 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::IndexSchema;
+
+/*
+ * Columns numbered after `indnkeyatts` are `INCLUDE` columns,
+ * and not part of the index key.
+ */
+pub const QUERY_INDEX_SCHEMA_SQL: &str = r#"
+    SELECT
+        n.nspname AS schema_name,
+        t.relname AS table_name,
+        i.relname AS index_name,
+        ARRAY(
+            SELECT pg_catalog.pg_get_indexdef(ix.indexrelid, k, true)
+            FROM generate_series(1, ix.indnkeyatts) AS k
+            ORDER BY k
+        ) AS columns,
+        ARRAY(
+            SELECT pg_catalog.pg_get_indexdef(ix.indexrelid, k, true)
+            FROM generate_series(ix.indnkeyatts + 1, ix.indnatts) AS k
+            ORDER BY k
+        ) AS include_columns,
+        ix.indisunique AS is_unique,
+        am.amname AS index_method,
+        pg_catalog.pg_get_expr(ix.indpred, ix.indrelid, true) AS predicate
+    FROM pg_catalog.pg_index ix
+    JOIN pg_catalog.pg_class i ON i.oid = ix.indexrelid
+    JOIN pg_catalog.pg_class t ON t.oid = ix.indrelid
+    JOIN pg_catalog.pg_namespace n ON n.oid = t.relnamespace
+    JOIN pg_catalog.pg_am am ON am.oid = i.relam
+    WHERE n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND n.nspname NOT LIKE 'pg_toast%'
+    AND t.relname != 'seaql_migrations'
+    AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_depend ext
+        WHERE ext.classid = 'pg_catalog.pg_class'::regclass
+        AND ext.objid = t.oid
+        AND ext.deptype = 'e'
+    )
+    ORDER BY n.nspname, t.relname, i.relname
+"#;
+
+pub fn build_index_schema(index_results: Vec<QueryResult>) -> Result<Vec<IndexSchema>, DbErr> {
+    index_results
+        .into_iter()
+        .map(|index_result| {
+            let schema = index_result.try_get::<String>("", "schema_name")?;
+            let table_name = index_result.try_get::<String>("", "table_name")?;
+            let name = index_result.try_get::<String>("", "index_name")?;
+            let columns = index_result.try_get::<Vec<String>>("", "columns")?;
+            let include_columns = index_result.try_get::<Vec<String>>("", "include_columns")?;
+            let is_unique = index_result.try_get::<bool>("", "is_unique")?;
+            let method = index_result.try_get::<String>("", "index_method")?;
+            let predicate = index_result.try_get::<Option<String>>("", "predicate")?;
+
+            Ok(IndexSchema {
+                schema,
+                table_name,
+                name,
+                columns,
+                include_columns,
+                is_unique,
+                method: Some(method),
+                predicate,
+            })
+        })
+        .collect()
+}
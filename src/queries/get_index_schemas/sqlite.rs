@@ -0,0 +1,97 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::IndexSchema;
+use crate::queries::sqlite_sql::normalise_sql;
+use crate::queries::sqlite_sql::tokenize_sql;
+use crate::queries::sqlite_sql::unquote_identifier;
+
+/*
+ * Indexes made for `UNIQUE` and `PRIMARY KEY` constraints have no SQL.
+ * So the indexed columns are taken from `pragma_index_info` instead.
+ */
+pub const QUERY_INDEX_SCHEMA_SQL: &str = r#"
+    SELECT
+        m.name AS table_name,
+        il.name AS index_name,
+        il."unique" AS is_unique,
+        (
+            SELECT group_concat(COALESCE(ii.name, '?'), ',')
+            FROM (
+                SELECT name FROM pragma_index_info(il.name) ORDER BY seqno
+            ) ii
+        ) AS column_names,
+        i.sql AS index_sql
+    FROM sqlite_master m
+    JOIN pragma_index_list(m.name) il
+    LEFT JOIN sqlite_master i ON i.type = "index" AND i.name = il.name
+    WHERE
+        m.type = "table" AND
+        m.name != "seaql_migrations" AND
+        m.name != "sqlite_sequence"
+    ORDER BY m.name, il.name
+"#;
+
+pub fn build_index_schema(index_results: Vec<QueryResult>) -> Result<Vec<IndexSchema>, DbErr> {
+    index_results
+        .into_iter()
+        .map(|index_result| {
+            let table_name = index_result.try_get::<String>("", "table_name")?;
+            let name = index_result.try_get::<String>("", "index_name")?;
+            let is_unique = index_result.try_get::<bool>("", "is_unique")?;
+            let column_names = index_result.try_get::<Option<String>>("", "column_names")?;
+            let index_sql = index_result.try_get::<Option<String>>("", "index_sql")?;
+
+            let (columns, predicate) = match index_sql {
+                Some(index_sql) => parse_index_sql(&index_sql),
+                None => {
+                    let columns = column_names
+                        .unwrap_or_default()
+                        .split(',')
+                        .map(str::to_string)
+                        .collect();
+
+                    (columns, None)
+                }
+            };
+
+            Ok(IndexSchema {
+                schema: "main".to_string(),
+                table_name,
+                name,
+                columns,
+                include_columns: Vec::new(),
+                is_unique,
+                method: None,
+                predicate,
+            })
+        })
+        .collect()
+}
+
+///
+/// Pulls the indexed columns, and any `WHERE` clause, out of a `CREATE INDEX`.
+///
+fn parse_index_sql(index_sql: &str) -> (Vec<String>, Option<String>) {
+    let tokens = tokenize_sql(index_sql);
+    let columns = tokens
+        .iter()
+        .find_map(|token| token.strip_prefix('(')?.strip_suffix(')'))
+        .map(|columns| {
+            tokenize_sql(columns)
+                .split(|token| *token == ",")
+                .map(|column| match column {
+                    [identifier] => unquote_identifier(identifier),
+                    _ => normalise_sql(&column.join(" ")),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let predicate = tokens
+        .iter()
+        .position(|token| token.eq_ignore_ascii_case("WHERE"))
+        .map(|where_index| normalise_sql(&tokens[where_index + 1..].join(" ")));
+
+    (columns, predicate)
+}
@@ -0,0 +1,54 @@
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+use ::sea_orm_migration::sea_orm::Statement;
+
+mod postgres;
+
+///
+/// A schema, as made with `CREATE SCHEMA`.
+///
+/// This is called a namespace to avoid confusion with the other `Schema` types.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct NamespaceSchema {
+    pub name: String,
+}
+
+pub async fn get_namespace_schemas<C>(db_connection: &C) -> Result<Vec<NamespaceSchema>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = db_connection.get_database_backend();
+    let Some(sql) = get_namespace_query_sql(db_backend) else {
+        return Ok(Vec::new());
+    };
+    let list_namespaces_statement = Statement::from_string(db_backend, sql.to_string());
+
+    let namespace_results = db_connection.query_all(list_namespaces_statement).await?;
+
+    let mut namespace_schemas = build_namespace_schema(db_backend, namespace_results)?;
+    namespace_schemas.sort();
+
+    Ok(namespace_schemas)
+}
+
+fn build_namespace_schema(
+    db_backend: DatabaseBackend,
+    namespace_results: Vec<QueryResult>,
+) -> Result<Vec<NamespaceSchema>, DbErr> {
+    match db_backend {
+        DatabaseBackend::MySql => Ok(Vec::new()),
+        DatabaseBackend::Postgres => postgres::build_namespace_schema(namespace_results),
+        DatabaseBackend::Sqlite => Ok(Vec::new()),
+    }
+}
+
+fn get_namespace_query_sql(db_backend: DatabaseBackend) -> Option<&'static str> {
+    match db_backend {
+        DatabaseBackend::MySql => None,
+        DatabaseBackend::Postgres => Some(postgres::QUERY_NAMESPACE_SCHEMA_SQL),
+        DatabaseBackend::Sqlite => None,
+    }
+}
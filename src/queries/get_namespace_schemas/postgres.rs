@@ -0,0 +1,30 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::NamespaceSchema;
+
+/*
+ * `pg_toast` and `pg_temp` schemas are made by Postgres itself,
+ * and come and go outside of migrations.
+ */
+pub const QUERY_NAMESPACE_SCHEMA_SQL: &str = r#"
+    SELECT n.nspname AS schema_name
+    FROM pg_catalog.pg_namespace n
+    WHERE n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND n.nspname NOT LIKE 'pg_toast%'
+    AND n.nspname NOT LIKE 'pg_temp%'
+    ORDER BY n.nspname
+"#;
+
+pub fn build_namespace_schema(
+    namespace_results: Vec<QueryResult>,
+) -> Result<Vec<NamespaceSchema>, DbErr> {
+    namespace_results
+        .into_iter()
+        .map(|namespace_result| {
+            let name = namespace_result.try_get::<String>("", "schema_name")?;
+
+            Ok(NamespaceSchema { name })
+        })
+        .collect()
+}
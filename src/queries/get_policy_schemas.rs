@@ -0,0 +1,69 @@
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+use ::sea_orm_migration::sea_orm::Statement;
+
+mod postgres;
+
+///
+/// A row level security policy on a table.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct PolicySchema {
+    pub schema: String,
+    pub table_name: String,
+    pub name: String,
+
+    /// The command the policy applies to. i.e. `SELECT`, or `ALL`.
+    pub command: String,
+
+    /// False for a `RESTRICTIVE` policy.
+    pub is_permissive: bool,
+
+    /// The roles the policy applies to, in name order.
+    pub roles: Vec<String>,
+
+    /// The `USING` expression.
+    pub using: Option<String>,
+
+    /// The `WITH CHECK` expression.
+    pub with_check: Option<String>,
+}
+
+pub async fn get_policy_schemas<C>(db_connection: &C) -> Result<Vec<PolicySchema>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = db_connection.get_database_backend();
+    let Some(sql) = get_policy_query_sql(db_backend) else {
+        return Ok(Vec::new());
+    };
+    let list_policies_statement = Statement::from_string(db_backend, sql.to_string());
+
+    let policy_results = db_connection.query_all(list_policies_statement).await?;
+
+    let mut policy_schemas = build_policy_schema(db_backend, policy_results)?;
+    policy_schemas.sort();
+
+    Ok(policy_schemas)
+}
+
+fn build_policy_schema(
+    db_backend: DatabaseBackend,
+    policy_results: Vec<QueryResult>,
+) -> Result<Vec<PolicySchema>, DbErr> {
+    match db_backend {
+        DatabaseBackend::MySql => Ok(Vec::new()),
+        DatabaseBackend::Postgres => postgres::build_policy_schema(policy_results),
+        DatabaseBackend::Sqlite => Ok(Vec::new()),
+    }
+}
+
+fn get_policy_query_sql(db_backend: DatabaseBackend) -> Option<&'static str> {
+    match db_backend {
+        DatabaseBackend::MySql => None,
+        DatabaseBackend::Postgres => Some(postgres::QUERY_POLICY_SCHEMA_SQL),
+        DatabaseBackend::Sqlite => None,
+    }
+}
@@ -0,0 +1,65 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::PolicySchema;
+
+/*
+ * A role of 0 is `PUBLIC`.
+ */
+pub const QUERY_POLICY_SCHEMA_SQL: &str = r#"
+    SELECT
+        n.nspname AS schema_name,
+        c.relname AS table_name,
+        p.polname AS policy_name,
+        CASE p.polcmd
+            WHEN 'r' THEN 'SELECT'
+            WHEN 'a' THEN 'INSERT'
+            WHEN 'w' THEN 'UPDATE'
+            WHEN 'd' THEN 'DELETE'
+            WHEN '*' THEN 'ALL'
+        END AS command,
+        p.polpermissive AS is_permissive,
+        ARRAY(
+            SELECT CASE WHEN r.oid = 0
+                THEN 'PUBLIC'
+                ELSE pg_catalog.pg_get_userbyid(r.oid)::text
+            END
+            FROM unnest(p.polroles) AS r(oid)
+            ORDER BY 1
+        ) AS roles,
+        pg_catalog.pg_get_expr(p.polqual, p.polrelid, true) AS using_expression,
+        pg_catalog.pg_get_expr(p.polwithcheck, p.polrelid, true) AS with_check_expression
+    FROM pg_catalog.pg_policy p
+    JOIN pg_catalog.pg_class c ON c.oid = p.polrelid
+    JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+    WHERE n.nspname NOT IN ('pg_catalog', 'information_schema')
+    ORDER BY n.nspname, c.relname, p.polname
+"#;
+
+pub fn build_policy_schema(policy_results: Vec<QueryResult>) -> Result<Vec<PolicySchema>, DbErr> {
+    policy_results
+        .into_iter()
+        .map(|policy_result| {
+            let schema = policy_result.try_get::<String>("", "schema_name")?;
+            let table_name = policy_result.try_get::<String>("", "table_name")?;
+            let name = policy_result.try_get::<String>("", "policy_name")?;
+            let command = policy_result.try_get::<String>("", "command")?;
+            let is_permissive = policy_result.try_get::<bool>("", "is_permissive")?;
+            let roles = policy_result.try_get::<Vec<String>>("", "roles")?;
+            let using = policy_result.try_get::<Option<String>>("", "using_expression")?;
+            let with_check =
+                policy_result.try_get::<Option<String>>("", "with_check_expression")?;
+
+            Ok(PolicySchema {
+                schema,
+                table_name,
+                name,
+                command,
+                is_permissive,
+                roles,
+                using,
+                with_check,
+            })
+        })
+        .collect()
+}
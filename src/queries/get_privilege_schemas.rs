@@ -0,0 +1,66 @@
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+use ::sea_orm_migration::sea_orm::Statement;
+
+mod postgres;
+
+///
+/// A privilege granted on a table, or on a column of a table.
+///
+/// Privileges held by the owner of the table are not included.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct PrivilegeSchema {
+    pub schema: String,
+    pub table_name: String,
+
+    /// This is `None` for privileges on the whole table.
+    pub column_name: Option<String>,
+
+    /// The role granted the privilege, or `PUBLIC`.
+    pub grantee: String,
+
+    /// i.e. `SELECT`.
+    pub privilege: String,
+
+    pub is_grantable: bool,
+}
+
+pub async fn get_privilege_schemas<C>(db_connection: &C) -> Result<Vec<PrivilegeSchema>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = db_connection.get_database_backend();
+    let Some(sql) = get_privilege_query_sql(db_backend) else {
+        return Ok(Vec::new());
+    };
+    let list_privileges_statement = Statement::from_string(db_backend, sql.to_string());
+
+    let privilege_results = db_connection.query_all(list_privileges_statement).await?;
+
+    let mut privilege_schemas = build_privilege_schema(db_backend, privilege_results)?;
+    privilege_schemas.sort();
+
+    Ok(privilege_schemas)
+}
+
+fn build_privilege_schema(
+    db_backend: DatabaseBackend,
+    privilege_results: Vec<QueryResult>,
+) -> Result<Vec<PrivilegeSchema>, DbErr> {
+    match db_backend {
+        DatabaseBackend::MySql => Ok(Vec::new()),
+        DatabaseBackend::Postgres => postgres::build_privilege_schema(privilege_results),
+        DatabaseBackend::Sqlite => Ok(Vec::new()),
+    }
+}
+
+fn get_privilege_query_sql(db_backend: DatabaseBackend) -> Option<&'static str> {
+    match db_backend {
+        DatabaseBackend::MySql => None,
+        DatabaseBackend::Postgres => Some(postgres::QUERY_PRIVILEGE_SCHEMA_SQL),
+        DatabaseBackend::Sqlite => None,
+    }
+}
@@ -0,0 +1,79 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::PrivilegeSchema;
+
+/*
+ * A table with no grants has a `NULL` ACL. Once anything is granted the
+ * owner's own privileges are written into the ACL as well, and stay there
+ * after a `REVOKE`. So these are skipped, as they would always differ.
+ *
+ * A grantee of 0 is `PUBLIC`.
+ */
+pub const QUERY_PRIVILEGE_SCHEMA_SQL: &str = r#"
+    SELECT
+        n.nspname AS schema_name,
+        c.relname AS table_name,
+        NULL::text AS column_name,
+        CASE WHEN acl.grantee = 0
+            THEN 'PUBLIC'
+            ELSE pg_catalog.pg_get_userbyid(acl.grantee)::text
+        END AS grantee,
+        acl.privilege_type AS privilege,
+        acl.is_grantable AS is_grantable
+    FROM pg_catalog.pg_class c
+    JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+    CROSS JOIN LATERAL aclexplode(c.relacl) AS acl
+    WHERE c.relkind IN ('r', 'p', 'v', 'm', 'f')
+    AND acl.grantee != c.relowner
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+
+    UNION ALL
+
+    SELECT
+        n.nspname AS schema_name,
+        c.relname AS table_name,
+        a.attname::text AS column_name,
+        CASE WHEN acl.grantee = 0
+            THEN 'PUBLIC'
+            ELSE pg_catalog.pg_get_userbyid(acl.grantee)::text
+        END AS grantee,
+        acl.privilege_type AS privilege,
+        acl.is_grantable AS is_grantable
+    FROM pg_catalog.pg_attribute a
+    JOIN pg_catalog.pg_class c ON c.oid = a.attrelid
+    JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+    CROSS JOIN LATERAL aclexplode(a.attacl) AS acl
+    WHERE c.relkind IN ('r', 'p', 'v', 'm', 'f')
+    AND a.attnum > 0
+    AND NOT a.attisdropped
+    AND acl.grantee != c.relowner
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+
+    ORDER BY schema_name, table_name, column_name, grantee, privilege
+"#;
+
+pub fn build_privilege_schema(
+    privilege_results: Vec<QueryResult>,
+) -> Result<Vec<PrivilegeSchema>, DbErr> {
+    privilege_results
+        .into_iter()
+        .map(|privilege_result| {
+            let schema = privilege_result.try_get::<String>("", "schema_name")?;
+            let table_name = privilege_result.try_get::<String>("", "table_name")?;
+            let column_name = privilege_result.try_get::<Option<String>>("", "column_name")?;
+            let grantee = privilege_result.try_get::<String>("", "grantee")?;
+            let privilege = privilege_result.try_get::<String>("", "privilege")?;
+            let is_grantable = privilege_result.try_get::<bool>("", "is_grantable")?;
+
+            Ok(PrivilegeSchema {
+                schema,
+                table_name,
+                column_name,
+                grantee,
+                privilege,
+                is_grantable,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,59 @@
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+use ::sea_orm_migration::sea_orm::Statement;
+
+mod postgres;
+
+///
+/// Row level security settings, for a table which has them turned on.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct RowSecuritySchema {
+    pub schema: String,
+    pub table_name: String,
+
+    /// Set with `ENABLE ROW LEVEL SECURITY`.
+    pub is_enabled: bool,
+
+    /// Set with `FORCE ROW LEVEL SECURITY`.
+    pub is_forced: bool,
+}
+
+pub async fn get_row_security_schemas<C>(db_connection: &C) -> Result<Vec<RowSecuritySchema>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = db_connection.get_database_backend();
+    let Some(sql) = get_row_security_query_sql(db_backend) else {
+        return Ok(Vec::new());
+    };
+    let list_row_security_statement = Statement::from_string(db_backend, sql.to_string());
+
+    let row_security_results = db_connection.query_all(list_row_security_statement).await?;
+
+    let mut row_security_schemas = build_row_security_schema(db_backend, row_security_results)?;
+    row_security_schemas.sort();
+
+    Ok(row_security_schemas)
+}
+
+fn build_row_security_schema(
+    db_backend: DatabaseBackend,
+    row_security_results: Vec<QueryResult>,
+) -> Result<Vec<RowSecuritySchema>, DbErr> {
+    match db_backend {
+        DatabaseBackend::MySql => Ok(Vec::new()),
+        DatabaseBackend::Postgres => postgres::build_row_security_schema(row_security_results),
+        DatabaseBackend::Sqlite => Ok(Vec::new()),
+    }
+}
+
+fn get_row_security_query_sql(db_backend: DatabaseBackend) -> Option<&'static str> {
+    match db_backend {
+        DatabaseBackend::MySql => None,
+        DatabaseBackend::Postgres => Some(postgres::QUERY_ROW_SECURITY_SCHEMA_SQL),
+        DatabaseBackend::Sqlite => None,
+    }
+}
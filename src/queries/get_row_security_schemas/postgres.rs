@@ -0,0 +1,38 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::RowSecuritySchema;
+
+pub const QUERY_ROW_SECURITY_SCHEMA_SQL: &str = r#"
+    SELECT
+        n.nspname AS schema_name,
+        c.relname AS table_name,
+        c.relrowsecurity AS is_enabled,
+        c.relforcerowsecurity AS is_forced
+    FROM pg_catalog.pg_class c
+    JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+    WHERE (c.relrowsecurity OR c.relforcerowsecurity)
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    ORDER BY n.nspname, c.relname
+"#;
+
+pub fn build_row_security_schema(
+    row_security_results: Vec<QueryResult>,
+) -> Result<Vec<RowSecuritySchema>, DbErr> {
+    row_security_results
+        .into_iter()
+        .map(|row_security_result| {
+            let schema = row_security_result.try_get::<String>("", "schema_name")?;
+            let table_name = row_security_result.try_get::<String>("", "table_name")?;
+            let is_enabled = row_security_result.try_get::<bool>("", "is_enabled")?;
+            let is_forced = row_security_result.try_get::<bool>("", "is_forced")?;
+
+            Ok(RowSecuritySchema {
+                schema,
+                table_name,
+                is_enabled,
+                is_forced,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,144 @@
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DbErr;
+
+use super::get_constraint_schemas;
+use super::get_extension_schemas;
+use super::get_function_schemas;
+use super::get_index_schemas;
+use super::get_namespace_schemas;
+use super::get_policy_schemas;
+use super::get_privilege_schemas;
+use super::get_row_security_schemas;
+use super::get_sequence_schemas;
+use super::get_table_schemas;
+use super::get_trigger_schemas;
+use super::get_type_schemas;
+use super::get_view_schemas;
+use super::ConstraintSchema;
+use super::ExtensionSchema;
+use super::FunctionSchema;
+use super::IndexSchema;
+use super::NamespaceSchema;
+use super::PolicySchema;
+use super::PrivilegeSchema;
+use super::RowSecuritySchema;
+use super::SequenceSchema;
+use super::TableSchema;
+use super::TriggerSchema;
+use super::TypeSchema;
+use super::ViewSchema;
+use crate::ReversibilityOptions;
+
+///
+/// Everything that is compared about a database at a single point in time.
+///
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct SchemaSnapshot {
+    pub namespaces: Vec<NamespaceSchema>,
+    pub extensions: Vec<ExtensionSchema>,
+    pub tables: Vec<TableSchema>,
+    pub types: Vec<TypeSchema>,
+    pub indexes: Vec<IndexSchema>,
+    pub constraints: Vec<ConstraintSchema>,
+    pub sequences: Vec<SequenceSchema>,
+    pub views: Vec<ViewSchema>,
+    pub triggers: Vec<TriggerSchema>,
+    pub functions: Vec<FunctionSchema>,
+
+    /// Security is only read when checking it is turned on in the options.
+    pub row_securities: Vec<RowSecuritySchema>,
+    pub policies: Vec<PolicySchema>,
+    pub privileges: Vec<PrivilegeSchema>,
+}
+
+impl SchemaSnapshot {
+    ///
+    /// Removes everything which is not within one of the given schemas.
+    ///
+    fn retain_schemas(&mut self, schemas: &[String]) {
+        let is_kept = |schema: &String| schemas.contains(schema);
+
+        self.namespaces.retain(|namespace| is_kept(&namespace.name));
+        self.extensions
+            .retain(|extension| is_kept(&extension.schema));
+        self.tables.retain(|table| is_kept(&table.schema));
+        self.types
+            .retain(|type_schema| is_kept(&type_schema.schema));
+        self.indexes.retain(|index| is_kept(&index.schema));
+        self.constraints
+            .retain(|constraint| is_kept(&constraint.schema));
+        self.sequences.retain(|sequence| is_kept(&sequence.schema));
+        self.views.retain(|view| is_kept(&view.schema));
+        self.triggers.retain(|trigger| is_kept(&trigger.schema));
+        self.functions.retain(|function| is_kept(&function.schema));
+        self.row_securities
+            .retain(|row_security| is_kept(&row_security.schema));
+        self.policies.retain(|policy| is_kept(&policy.schema));
+        self.privileges
+            .retain(|privilege| is_kept(&privilege.schema));
+    }
+
+    fn remove_comments(&mut self) {
+        for table in &mut self.tables {
+            table.comment = None;
+            for column in &mut table.columns {
+                column.comment = None;
+            }
+        }
+    }
+}
+
+pub async fn get_schema_snapshot<C>(
+    db_connection: &C,
+    options: &ReversibilityOptions,
+) -> Result<SchemaSnapshot, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let namespaces = get_namespace_schemas(db_connection).await?;
+    let extensions = get_extension_schemas(db_connection).await?;
+    let tables = get_table_schemas(db_connection).await?;
+    let types = get_type_schemas(db_connection).await?;
+    let indexes = get_index_schemas(db_connection).await?;
+    let constraints = get_constraint_schemas(db_connection).await?;
+    let sequences = get_sequence_schemas(db_connection).await?;
+    let views = get_view_schemas(db_connection).await?;
+    let triggers = get_trigger_schemas(db_connection).await?;
+    let functions = get_function_schemas(db_connection).await?;
+
+    let (row_securities, policies, privileges) = if options.is_checking_security {
+        (
+            get_row_security_schemas(db_connection).await?,
+            get_policy_schemas(db_connection).await?,
+            get_privilege_schemas(db_connection).await?,
+        )
+    } else {
+        (Vec::new(), Vec::new(), Vec::new())
+    };
+
+    let mut snapshot = SchemaSnapshot {
+        namespaces,
+        extensions,
+        tables,
+        types,
+        indexes,
+        constraints,
+        sequences,
+        views,
+        triggers,
+        functions,
+        row_securities,
+        policies,
+        privileges,
+    };
+
+    if let Some(schemas) = &options.schemas {
+        snapshot.retain_schemas(schemas);
+    }
+
+    if !options.is_checking_comments {
+        snapshot.remove_comments();
+    }
+
+    Ok(snapshot)
+}
@@ -0,0 +1,66 @@
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+use ::sea_orm_migration::sea_orm::Statement;
+
+mod postgres;
+
+///
+/// A sequence, including those created implicitly for `serial` and identity columns.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct SequenceSchema {
+    pub schema: String,
+    pub name: String,
+
+    /// i.e. `bigint`.
+    pub data_type: String,
+
+    pub start_value: i64,
+    pub increment: i64,
+    pub min_value: i64,
+    pub max_value: i64,
+    pub is_cycle: bool,
+
+    /// The column which owns this sequence, as `table.column`.
+    /// Owned sequences are dropped along with their column.
+    pub owned_by: Option<String>,
+}
+
+pub async fn get_sequence_schemas<C>(db_connection: &C) -> Result<Vec<SequenceSchema>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = db_connection.get_database_backend();
+    let Some(sql) = get_sequence_query_sql(db_backend) else {
+        return Ok(Vec::new());
+    };
+    let list_sequences_statement = Statement::from_string(db_backend, sql.to_string());
+
+    let sequence_results = db_connection.query_all(list_sequences_statement).await?;
+
+    let mut sequence_schemas = build_sequence_schema(db_backend, sequence_results)?;
+    sequence_schemas.sort();
+
+    Ok(sequence_schemas)
+}
+
+fn build_sequence_schema(
+    db_backend: DatabaseBackend,
+    sequence_results: Vec<QueryResult>,
+) -> Result<Vec<SequenceSchema>, DbErr> {
+    match db_backend {
+        DatabaseBackend::MySql => Ok(Vec::new()),
+        DatabaseBackend::Postgres => postgres::build_sequence_schema(sequence_results),
+        DatabaseBackend::Sqlite => Ok(Vec::new()),
+    }
+}
+
+fn get_sequence_query_sql(db_backend: DatabaseBackend) -> Option<&'static str> {
+    match db_backend {
+        DatabaseBackend::MySql => None,
+        DatabaseBackend::Postgres => Some(postgres::QUERY_SEQUENCE_SCHEMA_SQL),
+        DatabaseBackend::Sqlite => None,
+    }
+}
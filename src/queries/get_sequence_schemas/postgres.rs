@@ -0,0 +1,71 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::SequenceSchema;
+
+/*
+ * `serial` columns own their sequence with an 'a' dependency,
+ * and identity columns with an 'i' dependency.
+ */
+pub const QUERY_SEQUENCE_SCHEMA_SQL: &str = r#"
+    SELECT
+        n.nspname AS schema_name,
+        c.relname AS sequence_name,
+        pg_catalog.format_type(s.seqtypid, NULL) AS data_type,
+        s.seqstart AS start_value,
+        s.seqincrement AS increment,
+        s.seqmin AS min_value,
+        s.seqmax AS max_value,
+        s.seqcycle AS is_cycle,
+        oc.relname || '.' || a.attname AS owned_by
+    FROM pg_catalog.pg_sequence s
+    JOIN pg_catalog.pg_class c ON c.oid = s.seqrelid
+    JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+    LEFT JOIN pg_catalog.pg_depend d
+        ON d.classid = 'pg_catalog.pg_class'::regclass
+        AND d.objid = c.oid
+        AND d.refclassid = 'pg_catalog.pg_class'::regclass
+        AND d.deptype IN ('a', 'i')
+    LEFT JOIN pg_catalog.pg_class oc ON oc.oid = d.refobjid
+    LEFT JOIN pg_catalog.pg_attribute a
+        ON a.attrelid = d.refobjid AND a.attnum = d.refobjsubid
+    WHERE n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_depend ext
+        WHERE ext.classid = 'pg_catalog.pg_class'::regclass
+        AND ext.objid = c.oid
+        AND ext.deptype = 'e'
+    )
+    ORDER BY n.nspname, c.relname
+"#;
+
+pub fn build_sequence_schema(
+    sequence_results: Vec<QueryResult>,
+) -> Result<Vec<SequenceSchema>, DbErr> {
+    sequence_results
+        .into_iter()
+        .map(|sequence_result| {
+            let schema = sequence_result.try_get::<String>("", "schema_name")?;
+            let name = sequence_result.try_get::<String>("", "sequence_name")?;
+            let data_type = sequence_result.try_get::<String>("", "data_type")?;
+            let start_value = sequence_result.try_get::<i64>("", "start_value")?;
+            let increment = sequence_result.try_get::<i64>("", "increment")?;
+            let min_value = sequence_result.try_get::<i64>("", "min_value")?;
+            let max_value = sequence_result.try_get::<i64>("", "max_value")?;
+            let is_cycle = sequence_result.try_get::<bool>("", "is_cycle")?;
+            let owned_by = sequence_result.try_get::<Option<String>>("", "owned_by")?;
+
+            Ok(SequenceSchema {
+                schema,
+                name,
+                data_type,
+                start_value,
+                increment,
+                min_value,
+                max_value,
+                is_cycle,
+                owned_by,
+            })
+        })
+        .collect()
+}
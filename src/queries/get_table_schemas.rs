@@ -1,17 +1,42 @@
 use ::sea_orm_migration::sea_orm::ConnectionTrait;
 use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
 use ::sea_orm_migration::sea_orm::QueryResult;
 use ::sea_orm_migration::sea_orm::Statement;
 use ::std::cmp::Ordering;
-use ::std::fmt::Debug;
 
+mod mysql;
 mod postgres;
 mod sqlite;
 
+///
+/// The structure of a single table.
+///
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct TableSchema {
-    pub name: String,
+    /// The schema the table is within.
+    /// For SQLite this is always `main`. MySQL only reads the current database,
+    /// and leaves this empty.
     pub schema: String,
+    pub name: String,
+
+    /// The columns, in the order they are within the table.
+    pub columns: Vec<ColumnSchema>,
+
+    /// Set with `COMMENT ON TABLE`. This is only read on Postgres.
+    pub comment: Option<String>,
+
+    /// The strategy and key of a partitioned table, i.e. `RANGE (created_at)`.
+    /// This is only read on Postgres.
+    pub partition_key: Option<String>,
+
+    /// The bounds of a partition, i.e. `FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')`.
+    /// This is only read on Postgres.
+    pub partition_bound: Option<String>,
+
+    /// The parent tables, in order. This includes the parent of a partition.
+    /// This is only read on Postgres.
+    pub inherits: Vec<String>,
 }
 
 impl PartialOrd for TableSchema {
@@ -22,13 +47,50 @@ impl PartialOrd for TableSchema {
 
 impl Ord for TableSchema {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.name
-            .cmp(&other.name)
-            .then_with(|| self.schema.cmp(&other.schema))
+        self.schema
+            .cmp(&other.schema)
+            .then_with(|| self.name.cmp(&other.name))
+            .then_with(|| self.columns.cmp(&other.columns))
+            .then_with(|| self.comment.cmp(&other.comment))
+            .then_with(|| self.partition_key.cmp(&other.partition_key))
+            .then_with(|| self.partition_bound.cmp(&other.partition_bound))
+            .then_with(|| self.inherits.cmp(&other.inherits))
     }
 }
 
-pub async fn get_table_schemas<C>(db_connection: &C) -> Vec<TableSchema>
+///
+/// A column within a table.
+///
+/// Values are as the database reports them, and so can differ between
+/// backends. Anything a backend does not support is left as `None`.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct ColumnSchema {
+    pub name: String,
+
+    /// The type including any modifiers, i.e. `varchar(255)`.
+    pub data_type: String,
+
+    pub is_nullable: bool,
+
+    /// The default expression, i.e. `now()`.
+    pub default: Option<String>,
+
+    /// How an identity column is generated, i.e. `ALWAYS` or `BY DEFAULT`.
+    /// For SQLite this is `AUTOINCREMENT`.
+    pub identity: Option<String>,
+
+    /// The expression used for a generated column.
+    pub generation: Option<String>,
+
+    /// The collation, when it differs from the default for the type.
+    pub collation: Option<String>,
+
+    /// Set with `COMMENT ON COLUMN`. This is only read on Postgres.
+    pub comment: Option<String>,
+}
+
+pub async fn get_table_schemas<C>(db_connection: &C) -> Result<Vec<TableSchema>, DbErr>
 where
     C: ConnectionTrait,
 {
@@ -36,23 +98,20 @@ where
     let sql = get_table_query_sql(db_backend);
     let list_tables_statement = Statement::from_string(db_backend, sql.to_string());
 
-    let table_results = db_connection
-        .query_all(list_tables_statement)
-        .await
-        .expect("expect results from listing tables");
+    let table_results = db_connection.query_all(list_tables_statement).await?;
 
-    let mut table_schemas = build_table_schema(db_backend, table_results);
+    let mut table_schemas = build_table_schema(db_backend, table_results)?;
     table_schemas.sort();
 
-    table_schemas
+    Ok(table_schemas)
 }
 
 fn build_table_schema(
     db_backend: DatabaseBackend,
     table_results: Vec<QueryResult>,
-) -> Vec<TableSchema> {
+) -> Result<Vec<TableSchema>, DbErr> {
     match db_backend {
-        DatabaseBackend::MySql => unimplemented!("MySql support is not yet implemented"),
+        DatabaseBackend::MySql => mysql::build_table_schema(table_results),
         DatabaseBackend::Postgres => postgres::build_table_schema(table_results),
         DatabaseBackend::Sqlite => sqlite::build_table_schema(table_results),
     }
@@ -60,7 +119,7 @@ fn build_table_schema(
 
 fn get_table_query_sql(db_backend: DatabaseBackend) -> &'static str {
     match db_backend {
-        DatabaseBackend::MySql => unimplemented!("MySql support is not yet implemented"),
+        DatabaseBackend::MySql => mysql::QUERY_TABLE_SCHEMA_SQL,
         DatabaseBackend::Postgres => postgres::QUERY_TABLE_SCHEMA_SQL,
         DatabaseBackend::Sqlite => sqlite::QUERY_TABLE_SCHEMA_SQL,
     }
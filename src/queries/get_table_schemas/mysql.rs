@@ -0,0 +1,87 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::ColumnSchema;
+use super::TableSchema;
+
+/*
+ * Values are cast to `CHAR`, as some MySQL versions return
+ * `information_schema` text as binary, which fails to decode as a `String`.
+ *
+ * Only the current database is read, so the schema is left empty.
+ */
+pub const QUERY_TABLE_SCHEMA_SQL: &str = r#"
+  SELECT
+      CAST(c.TABLE_NAME AS CHAR) AS table_name,
+      CAST(c.COLUMN_NAME AS CHAR) AS column_name,
+      CAST(c.COLUMN_TYPE AS CHAR) AS data_type,
+      CAST(c.IS_NULLABLE AS CHAR) AS is_nullable,
+      CAST(c.COLUMN_DEFAULT AS CHAR) AS column_default,
+      CAST(c.EXTRA AS CHAR) AS extra,
+      CAST(c.GENERATION_EXPRESSION AS CHAR) AS generation_expression,
+      CASE WHEN c.COLLATION_NAME != t.TABLE_COLLATION
+          THEN CAST(c.COLLATION_NAME AS CHAR)
+      END AS collation_name
+  FROM information_schema.COLUMNS c
+  JOIN information_schema.TABLES t
+      ON t.TABLE_SCHEMA = c.TABLE_SCHEMA
+      AND t.TABLE_NAME = c.TABLE_NAME
+  WHERE c.TABLE_SCHEMA = DATABASE()
+  AND t.TABLE_TYPE = 'BASE TABLE'
+  AND c.TABLE_NAME != 'seaql_migrations'
+  ORDER BY c.TABLE_NAME, c.ORDINAL_POSITION
+"#;
+
+pub fn build_table_schema(table_results: Vec<QueryResult>) -> Result<Vec<TableSchema>, DbErr> {
+    let mut all_table_schemas: Vec<TableSchema> = Vec::new();
+
+    for table_result in table_results {
+        let name = table_result.try_get::<String>("", "table_name")?;
+
+        let is_new_table = !matches!(
+            all_table_schemas.last(),
+            Some(table_schema) if table_schema.name == name
+        );
+        if is_new_table {
+            all_table_schemas.push(TableSchema {
+                schema: String::new(),
+                name,
+                columns: Vec::new(),
+                comment: None,
+                partition_key: None,
+                partition_bound: None,
+                inherits: Vec::new(),
+            });
+        }
+
+        let table_schema = all_table_schemas
+            .last_mut()
+            .expect("expect a table schema to be present");
+        table_schema
+            .columns
+            .push(build_column_schema_mysql(&table_result)?);
+    }
+
+    Ok(all_table_schemas)
+}
+
+fn build_column_schema_mysql(table_result: &QueryResult) -> Result<ColumnSchema, DbErr> {
+    let is_nullable: String = table_result.try_get("", "is_nullable")?;
+    let extra: Option<String> = table_result.try_get("", "extra")?;
+    let generation: Option<String> = table_result.try_get("", "generation_expression")?;
+
+    let is_auto_increment = extra
+        .as_deref()
+        .is_some_and(|extra| extra.to_lowercase().contains("auto_increment"));
+
+    Ok(ColumnSchema {
+        name: table_result.try_get("", "column_name")?,
+        data_type: table_result.try_get("", "data_type")?,
+        is_nullable: is_nullable == "YES",
+        default: table_result.try_get("", "column_default")?,
+        identity: is_auto_increment.then(|| "AUTO_INCREMENT".to_string()),
+        generation: generation.filter(|generation| !generation.is_empty()),
+        collation: table_result.try_get("", "collation_name")?,
+        comment: None,
+    })
+}
@@ -1,150 +1,128 @@
+use ::sea_orm_migration::sea_orm::DbErr;
 use ::sea_orm_migration::sea_orm::QueryResult;
-use ::sea_orm_migration::sea_orm::TryGetable;
-use ::std::fmt::Debug;
-use ::std::fmt::Write;
 
+use super::ColumnSchema;
 use super::TableSchema;
 
-pub const QUERY_TABLE_SCHEMA_SQL: &'static str = &r#"
+pub const QUERY_TABLE_SCHEMA_SQL: &str = r#"
   SELECT
-      table_name,
-      column_name,
-      is_nullable,
-      data_type,
-      character_maximum_length,
-      character_octet_length,
-      numeric_precision,
-      numeric_precision_radix,
-      numeric_scale,
-      datetime_precision,
-      interval_type,
-      interval_precision,
-      character_set_catalog,
-      character_set_schema,
-      character_set_name,
-      collation_catalog,
-      collation_schema,
-      collation_name,
-      domain_catalog,
-      domain_schema,
-      domain_name,
-      udt_catalog,
-      udt_schema,
-      udt_name,
-      scope_catalog,
-      scope_schema,
-      scope_name,
-      maximum_cardinality,
-      is_self_referencing,
-      is_identity,
-      identity_generation,
-      identity_start,
-      identity_increment,
-      identity_maximum,
-      identity_minimum,
-      identity_cycle,
-      is_generated,
-      generation_expression,
-      is_updatable
-  FROM information_schema.columns
-  WHERE table_schema not in ('pg_catalog', 'information_schema')
-  AND table_name != 'seaql_migrations'
-  ORDER BY table_name, column_name
+      n.nspname AS schema_name,
+      c.relname AS table_name,
+      a.attname AS column_name,
+      pg_catalog.format_type(a.atttypid, a.atttypmod) AS data_type,
+      NOT a.attnotnull AS is_nullable,
+      CASE WHEN a.attgenerated = ''
+          THEN pg_catalog.pg_get_expr(d.adbin, d.adrelid)
+      END AS column_default,
+      CASE a.attidentity
+          WHEN 'a' THEN 'ALWAYS'
+          WHEN 'd' THEN 'BY DEFAULT'
+      END AS identity_generation,
+      CASE WHEN a.attgenerated != ''
+          THEN pg_catalog.pg_get_expr(d.adbin, d.adrelid)
+      END AS generation_expression,
+      co.collname AS collation_name,
+      pg_catalog.obj_description(c.oid, 'pg_class') AS table_comment,
+      pg_catalog.col_description(c.oid, a.attnum) AS column_comment,
+      pg_catalog.pg_get_partkeydef(c.oid) AS partition_key,
+      pg_catalog.pg_get_expr(c.relpartbound, c.oid) AS partition_bound,
+      ARRAY(
+          SELECT i.inhparent::pg_catalog.regclass::text
+          FROM pg_catalog.pg_inherits i
+          WHERE i.inhrelid = c.oid
+          ORDER BY i.inhseqno
+      ) AS inherits
+  FROM pg_catalog.pg_attribute a
+  JOIN pg_catalog.pg_class c ON c.oid = a.attrelid
+  JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+  JOIN pg_catalog.pg_type t ON t.oid = a.atttypid
+  LEFT JOIN pg_catalog.pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+  LEFT JOIN pg_catalog.pg_collation co ON co.oid = a.attcollation AND a.attcollation != t.typcollation
+  WHERE a.attnum > 0
+  AND NOT a.attisdropped
+  AND c.relkind IN ('r', 'p', 'f')
+  AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+  AND c.relname != 'seaql_migrations'
+  AND NOT EXISTS (
+      SELECT 1 FROM pg_catalog.pg_depend ext
+      WHERE ext.classid = 'pg_catalog.pg_class'::regclass
+      AND ext.objid = c.oid
+      AND ext.deptype = 'e'
+  )
+  ORDER BY n.nspname, c.relname, a.attnum
 "#;
 
-pub fn build_table_schema(table_results: Vec<QueryResult>) -> Vec<TableSchema> {
+pub fn build_table_schema(table_results: Vec<QueryResult>) -> Result<Vec<TableSchema>, DbErr> {
     let mut all_table_schemas: Vec<TableSchema> = Vec::new();
 
-    let last_schema = table_results.into_iter().fold(
-        None as Option<TableSchema>,
-        |maybe_table_schema, table_result| {
-            let name = table_result
-                .try_get::<String>("", "table_name")
-                .expect("expect `table_name` to be present in SQL Query results");
+    for table_result in table_results {
+        let schema = table_result.try_get::<String>("", "schema_name")?;
+        let name = table_result.try_get::<String>("", "table_name")?;
 
-            if let Some(mut table_schema) = maybe_table_schema {
-                if table_schema.name != name {
-                    let mut temp = TableSchema {
-                        name,
-                        schema: String::new(),
-                    };
-                    ::std::mem::swap(&mut temp, &mut table_schema);
-                    all_table_schemas.push(temp);
-                }
+        let is_new_table = !matches!(
+            all_table_schemas.last(),
+            Some(table_schema) if table_schema.schema == schema && table_schema.name == name
+        );
+        if is_new_table {
+            let comment = table_result.try_get::<Option<String>>("", "table_comment")?;
+            let partition_key = table_result.try_get::<Option<String>>("", "partition_key")?;
+            let partition_bound = table_result.try_get::<Option<String>>("", "partition_bound")?;
+            let inherits = table_result.try_get::<Vec<String>>("", "inherits")?;
 
-                collect_table_schema_parts_postgres(&mut table_schema.schema, table_result);
-                return Some(table_schema);
-            } else {
-                let mut table_schema = match maybe_table_schema {
-                    None => TableSchema {
-                        name,
-                        schema: String::new(),
-                    },
-                    Some(t_schema) => t_schema,
-                };
+            all_table_schemas.push(TableSchema {
+                schema,
+                name,
+                columns: Vec::new(),
+                comment,
+                partition_key,
+                partition_bound,
+                inherits,
+            });
+        }
 
-                collect_table_schema_parts_postgres(&mut table_schema.schema, table_result);
-                return Some(table_schema);
-            }
-        },
-    );
-
-    if last_schema.is_some() {
-        all_table_schemas.push(last_schema.unwrap());
+        let table_schema = all_table_schemas
+            .last_mut()
+            .expect("expect a table schema to be present");
+        table_schema
+            .columns
+            .push(build_column_schema_postgres(&table_result)?);
     }
 
-    all_table_schemas
+    Ok(all_table_schemas)
 }
 
-///
-fn collect_table_schema_parts_postgres(dest: &mut String, table_result: QueryResult) {
-    add_schema_part::<String>(dest, &table_result, &"column_name");
-    add_schema_part::<String>(dest, &table_result, &"is_nullable");
-    add_schema_part::<String>(dest, &table_result, &"data_type");
-    add_schema_part::<Option<i32>>(dest, &table_result, &"character_maximum_length");
-    add_schema_part::<Option<i32>>(dest, &table_result, &"character_octet_length");
-    add_schema_part::<Option<i32>>(dest, &table_result, &"numeric_precision");
-    add_schema_part::<Option<i32>>(dest, &table_result, &"numeric_precision_radix");
-    add_schema_part::<Option<i32>>(dest, &table_result, &"numeric_scale");
-    add_schema_part::<Option<i32>>(dest, &table_result, &"datetime_precision");
-    add_schema_part::<Option<String>>(dest, &table_result, &"interval_type");
-    add_schema_part::<Option<i32>>(dest, &table_result, &"interval_precision");
-    add_schema_part::<Option<String>>(dest, &table_result, &"character_set_catalog");
-    add_schema_part::<Option<String>>(dest, &table_result, &"character_set_schema");
-    add_schema_part::<Option<String>>(dest, &table_result, &"character_set_name");
-    add_schema_part::<Option<String>>(dest, &table_result, &"collation_catalog");
-    add_schema_part::<Option<String>>(dest, &table_result, &"collation_schema");
-    add_schema_part::<Option<String>>(dest, &table_result, &"collation_name");
-    add_schema_part::<Option<String>>(dest, &table_result, &"domain_catalog");
-    add_schema_part::<Option<String>>(dest, &table_result, &"domain_schema");
-    add_schema_part::<Option<String>>(dest, &table_result, &"domain_name");
-    add_schema_part::<Option<String>>(dest, &table_result, &"udt_catalog");
-    add_schema_part::<Option<String>>(dest, &table_result, &"udt_schema");
-    add_schema_part::<Option<String>>(dest, &table_result, &"udt_name");
-    add_schema_part::<Option<String>>(dest, &table_result, &"scope_catalog");
-    add_schema_part::<Option<String>>(dest, &table_result, &"scope_schema");
-    add_schema_part::<Option<String>>(dest, &table_result, &"scope_name");
-    add_schema_part::<Option<i32>>(dest, &table_result, &"maximum_cardinality");
-    add_schema_part::<Option<String>>(dest, &table_result, &"is_self_referencing");
-    add_schema_part::<Option<String>>(dest, &table_result, &"is_identity");
-    add_schema_part::<Option<String>>(dest, &table_result, &"identity_generation");
-    add_schema_part::<Option<String>>(dest, &table_result, &"identity_start");
-    add_schema_part::<Option<String>>(dest, &table_result, &"identity_increment");
-    add_schema_part::<Option<String>>(dest, &table_result, &"identity_maximum");
-    add_schema_part::<Option<String>>(dest, &table_result, &"identity_minimum");
-    add_schema_part::<Option<String>>(dest, &table_result, &"identity_cycle");
-    add_schema_part::<Option<String>>(dest, &table_result, &"is_generated");
-    add_schema_part::<Option<String>>(dest, &table_result, &"generation_expression");
-    add_schema_part::<Option<String>>(dest, &table_result, &"is_updatable");
+fn build_column_schema_postgres(table_result: &QueryResult) -> Result<ColumnSchema, DbErr> {
+    let default: Option<String> = table_result.try_get("", "column_default")?;
+
+    Ok(ColumnSchema {
+        name: table_result.try_get("", "column_name")?,
+        data_type: table_result.try_get("", "data_type")?,
+        is_nullable: table_result.try_get("", "is_nullable")?,
+        default: default.map(normalise_column_default),
+        identity: table_result.try_get("", "identity_generation")?,
+        generation: table_result.try_get("", "generation_expression")?,
+        collation: table_result.try_get("", "collation_name")?,
+        comment: table_result.try_get("", "column_comment")?,
+    })
 }
 
-fn add_schema_part<T>(schema: &mut String, table_result: &QueryResult, column_name: &str)
-where
-    T: TryGetable + Debug,
-{
-    let part: T = table_result
-        .try_get::<T>("", column_name)
-        .expect("expect to be able to unwrap column");
+///
+/// Postgres writes sequence defaults relative to the current `search_path`,
+/// with a `regclass` cast. These are removed so the same sequence always
+/// compares as equal.
+///
+/// i.e. `nextval('public.book_id_seq'::regclass)` becomes `nextval('book_id_seq')`.
+///
+fn normalise_column_default(default: String) -> String {
+    let maybe_sequence = default
+        .strip_prefix("nextval('")
+        .and_then(|sequence| sequence.strip_suffix("'::regclass)"));
 
-    write!(schema, ", {:?}", part).expect("Writing to schema should work");
+    match maybe_sequence {
+        Some(sequence) => {
+            let sequence = sequence.strip_prefix("public.").unwrap_or(sequence);
+            format!("nextval('{sequence}')")
+        }
+        None => default,
+    }
 }
@@ -1,31 +1,127 @@
+use ::sea_orm_migration::sea_orm::DbErr;
 use ::sea_orm_migration::sea_orm::QueryResult;
+use ::std::collections::HashMap;
 
+use super::ColumnSchema;
 use super::TableSchema;
+use crate::queries::sqlite_sql::is_table_constraint;
+use crate::queries::sqlite_sql::split_table_definitions;
+use crate::queries::sqlite_sql::unquote_identifier;
 
-pub const QUERY_TABLE_SCHEMA_SQL: &'static str = &r#"
-  SELECT name as table_name, sql as table_sql
-      FROM sqlite_master
+pub const QUERY_TABLE_SCHEMA_SQL: &str = r#"
+  SELECT
+      m.name as table_name,
+      m.sql as table_sql,
+      p.name as column_name,
+      p.type as data_type,
+      p."notnull" as not_null,
+      p.dflt_value as column_default
+  FROM sqlite_master m
+  JOIN pragma_table_xinfo(m.name) p
   WHERE
-      type = "table" AND
-      name != "seaql_migrations" AND
-      name != "sqlite_sequence"
+      m.type = "table" AND
+      m.name != "seaql_migrations" AND
+      m.name != "sqlite_sequence" AND
+      p.hidden != 1
+  ORDER BY m.name, p.cid
 "#;
 
-pub fn build_table_schema(table_results: Vec<QueryResult>) -> Vec<TableSchema> {
-    table_results
-        .into_iter()
-        .map(|table_result| {
-            let name = table_result
-                .try_get::<String>("", "table_name")
-                .expect("expect `table_name` to be present in SQL Query results");
-            let sql_schema = table_result
-                .try_get::<String>("", "table_sql")
-                .expect("expect `table_sql` to be present in SQL Query results");
-
-            TableSchema {
+///
+/// The parts of a column only found in the `CREATE TABLE` SQL.
+///
+#[derive(Default)]
+struct ColumnDefinition {
+    identity: Option<String>,
+    generation: Option<String>,
+    collation: Option<String>,
+}
+
+pub fn build_table_schema(table_results: Vec<QueryResult>) -> Result<Vec<TableSchema>, DbErr> {
+    let mut all_table_schemas: Vec<TableSchema> = Vec::new();
+    let mut column_definitions: HashMap<String, ColumnDefinition> = HashMap::new();
+
+    for table_result in table_results {
+        let name = table_result.try_get::<String>("", "table_name")?;
+
+        let is_new_table = !matches!(
+            all_table_schemas.last(),
+            Some(table_schema) if table_schema.name == name
+        );
+        if is_new_table {
+            let table_sql = table_result.try_get::<String>("", "table_sql")?;
+            column_definitions = parse_column_definitions(&table_sql);
+
+            all_table_schemas.push(TableSchema {
+                schema: "main".to_string(),
                 name,
-                schema: sql_schema,
+                columns: Vec::new(),
+                comment: None,
+                partition_key: None,
+                partition_bound: None,
+                inherits: Vec::new(),
+            });
+        }
+
+        let table_schema = all_table_schemas
+            .last_mut()
+            .expect("expect a table schema to be present");
+        table_schema.columns.push(build_column_schema_sqlite(
+            &table_result,
+            &mut column_definitions,
+        )?);
+    }
+
+    Ok(all_table_schemas)
+}
+
+fn build_column_schema_sqlite(
+    table_result: &QueryResult,
+    column_definitions: &mut HashMap<String, ColumnDefinition>,
+) -> Result<ColumnSchema, DbErr> {
+    let name = table_result.try_get::<String>("", "column_name")?;
+    let data_type = table_result.try_get::<String>("", "data_type")?;
+    let not_null = table_result.try_get::<bool>("", "not_null")?;
+    let default = table_result.try_get::<Option<String>>("", "column_default")?;
+
+    let definition = column_definitions
+        .remove(&name.to_lowercase())
+        .unwrap_or_default();
+
+    Ok(ColumnSchema {
+        name,
+        data_type: data_type.to_lowercase(),
+        is_nullable: !not_null,
+        default,
+        identity: definition.identity,
+        generation: definition.generation,
+        collation: definition.collation,
+        comment: None,
+    })
+}
+
+fn parse_column_definitions(table_sql: &str) -> HashMap<String, ColumnDefinition> {
+    split_table_definitions(table_sql)
+        .into_iter()
+        .filter(|definition| !is_table_constraint(definition))
+        .map(|definition| {
+            let name = unquote_identifier(definition[0]).to_lowercase();
+            let mut column_definition = ColumnDefinition::default();
+
+            for (i, token) in definition.iter().enumerate() {
+                let next_token = definition.get(i + 1);
+
+                if token.eq_ignore_ascii_case("AUTOINCREMENT") {
+                    column_definition.identity = Some("AUTOINCREMENT".to_string());
+                } else if token.eq_ignore_ascii_case("COLLATE") {
+                    column_definition.collation = next_token.map(|t| unquote_identifier(t));
+                } else if token.eq_ignore_ascii_case("AS") {
+                    column_definition.generation = next_token
+                        .and_then(|t| t.strip_prefix('(')?.strip_suffix(')'))
+                        .map(|expression| expression.trim().to_string());
+                }
             }
+
+            (name, column_definition)
         })
         .collect()
 }
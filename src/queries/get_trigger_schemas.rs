@@ -0,0 +1,74 @@
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+use ::sea_orm_migration::sea_orm::Statement;
+
+mod postgres;
+mod sqlite;
+
+///
+/// A trigger on a table.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct TriggerSchema {
+    pub schema: String,
+    pub table_name: String,
+    pub name: String,
+
+    /// The SQL defining the trigger, with whitespace normalised.
+    pub definition: String,
+
+    /// `BEFORE`, `AFTER`, or `INSTEAD OF`.
+    pub timing: String,
+
+    /// The events which fire the trigger, in the order
+    /// `INSERT`, `UPDATE`, `DELETE`, and `TRUNCATE`.
+    pub events: Vec<String>,
+
+    /// The function the trigger executes, i.e. `audit_book()`.
+    /// SQLite triggers hold their body inline, and so this is `None`.
+    pub function: Option<String>,
+
+    /// The `WHEN` condition. This is only read on Postgres.
+    pub condition: Option<String>,
+
+    pub is_enabled: bool,
+}
+
+pub async fn get_trigger_schemas<C>(db_connection: &C) -> Result<Vec<TriggerSchema>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = db_connection.get_database_backend();
+    let Some(sql) = get_trigger_query_sql(db_backend) else {
+        return Ok(Vec::new());
+    };
+    let list_triggers_statement = Statement::from_string(db_backend, sql.to_string());
+
+    let trigger_results = db_connection.query_all(list_triggers_statement).await?;
+
+    let mut trigger_schemas = build_trigger_schema(db_backend, trigger_results)?;
+    trigger_schemas.sort();
+
+    Ok(trigger_schemas)
+}
+
+fn build_trigger_schema(
+    db_backend: DatabaseBackend,
+    trigger_results: Vec<QueryResult>,
+) -> Result<Vec<TriggerSchema>, DbErr> {
+    match db_backend {
+        DatabaseBackend::MySql => Ok(Vec::new()),
+        DatabaseBackend::Postgres => postgres::build_trigger_schema(trigger_results),
+        DatabaseBackend::Sqlite => sqlite::build_trigger_schema(trigger_results),
+    }
+}
+
+fn get_trigger_query_sql(db_backend: DatabaseBackend) -> Option<&'static str> {
+    match db_backend {
+        DatabaseBackend::MySql => None,
+        DatabaseBackend::Postgres => Some(postgres::QUERY_TRIGGER_SCHEMA_SQL),
+        DatabaseBackend::Sqlite => Some(sqlite::QUERY_TRIGGER_SCHEMA_SQL),
+    }
+}
@@ -0,0 +1,77 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::TriggerSchema;
+use crate::queries::sqlite_sql::normalise_sql;
+
+/*
+ * `tgtype` is a bitmask of the trigger timing, and events.
+ * Internal triggers, such as those backing foreign keys, are skipped.
+ *
+ * Postgres has no function to print just the `WHEN` condition,
+ * so it is taken from the full trigger definition.
+ */
+pub const QUERY_TRIGGER_SCHEMA_SQL: &str = r#"
+    SELECT
+        n.nspname AS schema_name,
+        c.relname AS table_name,
+        t.tgname AS trigger_name,
+        pg_catalog.pg_get_triggerdef(t.oid, true) AS trigger_sql,
+        CASE
+            WHEN t.tgtype & 2 != 0 THEN 'BEFORE'
+            WHEN t.tgtype & 64 != 0 THEN 'INSTEAD OF'
+            ELSE 'AFTER'
+        END AS timing,
+        ARRAY_REMOVE(ARRAY[
+            CASE WHEN t.tgtype & 4 != 0 THEN 'INSERT' END,
+            CASE WHEN t.tgtype & 16 != 0 THEN 'UPDATE' END,
+            CASE WHEN t.tgtype & 8 != 0 THEN 'DELETE' END,
+            CASE WHEN t.tgtype & 32 != 0 THEN 'TRUNCATE' END
+        ], NULL) AS events,
+        t.tgfoid::regprocedure::text AS function_name,
+        substring(pg_catalog.pg_get_triggerdef(t.oid, true) FROM 'WHEN \((.+)\) EXECUTE ') AS condition,
+        t.tgenabled != 'D' AS is_enabled
+    FROM pg_catalog.pg_trigger t
+    JOIN pg_catalog.pg_class c ON c.oid = t.tgrelid
+    JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+    WHERE NOT t.tgisinternal
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_depend ext
+        WHERE ext.classid = 'pg_catalog.pg_class'::regclass
+        AND ext.objid = c.oid
+        AND ext.deptype = 'e'
+    )
+    ORDER BY n.nspname, c.relname, t.tgname
+"#;
+
+pub fn build_trigger_schema(
+    trigger_results: Vec<QueryResult>,
+) -> Result<Vec<TriggerSchema>, DbErr> {
+    trigger_results
+        .into_iter()
+        .map(|trigger_result| {
+            let schema = trigger_result.try_get::<String>("", "schema_name")?;
+            let table_name = trigger_result.try_get::<String>("", "table_name")?;
+            let name = trigger_result.try_get::<String>("", "trigger_name")?;
+            let trigger_sql = trigger_result.try_get::<String>("", "trigger_sql")?;
+            let timing = trigger_result.try_get::<String>("", "timing")?;
+            let events = trigger_result.try_get::<Vec<String>>("", "events")?;
+            let function = trigger_result.try_get::<String>("", "function_name")?;
+            let condition = trigger_result.try_get::<Option<String>>("", "condition")?;
+            let is_enabled = trigger_result.try_get::<bool>("", "is_enabled")?;
+
+            Ok(TriggerSchema {
+                schema,
+                table_name,
+                name,
+                definition: normalise_sql(&trigger_sql),
+                timing,
+                events,
+                function: Some(function),
+                condition,
+                is_enabled,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,63 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::TriggerSchema;
+use crate::queries::sqlite_sql::normalise_sql;
+use crate::queries::sqlite_sql::tokenize_sql;
+
+pub const QUERY_TRIGGER_SCHEMA_SQL: &str = r#"
+    SELECT tbl_name as table_name, name as trigger_name, sql as trigger_sql
+        FROM sqlite_master
+    WHERE type = "trigger"
+"#;
+
+pub fn build_trigger_schema(
+    trigger_results: Vec<QueryResult>,
+) -> Result<Vec<TriggerSchema>, DbErr> {
+    trigger_results
+        .into_iter()
+        .map(|trigger_result| {
+            let table_name = trigger_result.try_get::<String>("", "table_name")?;
+            let name = trigger_result.try_get::<String>("", "trigger_name")?;
+            let trigger_sql = trigger_result.try_get::<String>("", "trigger_sql")?;
+
+            let (timing, events) = parse_trigger_sql(&trigger_sql);
+
+            Ok(TriggerSchema {
+                schema: "main".to_string(),
+                table_name,
+                name,
+                definition: normalise_sql(&trigger_sql),
+                timing,
+                events,
+                function: None,
+                condition: None,
+                is_enabled: true,
+            })
+        })
+        .collect()
+}
+
+///
+/// Reads the timing, and events, from the `CREATE TRIGGER` SQL.
+/// These are all found before the `ON` keyword.
+///
+/// When no timing is given SQLite defaults to `BEFORE`.
+///
+fn parse_trigger_sql(trigger_sql: &str) -> (String, Vec<String>) {
+    let mut timing = "BEFORE".to_string();
+    let mut events = Vec::new();
+
+    for token in tokenize_sql(trigger_sql) {
+        let token = token.to_uppercase();
+        match token.as_str() {
+            "ON" => break,
+            "BEFORE" | "AFTER" => timing = token,
+            "INSTEAD" => timing = "INSTEAD OF".to_string(),
+            "INSERT" | "UPDATE" | "DELETE" => events.push(token),
+            _ => {}
+        }
+    }
+
+    (timing, events)
+}
@@ -1,17 +1,52 @@
 use ::sea_orm_migration::sea_orm::ConnectionTrait;
 use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
 use ::sea_orm_migration::sea_orm::QueryResult;
 use ::sea_orm_migration::sea_orm::Statement;
 use ::std::cmp::Ordering;
 use ::std::fmt::Debug;
 
+mod mysql;
 mod postgres;
+mod sqlite;
 
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum TypeKind {
+    Enum,
+    Domain,
+    Composite,
+    Range,
+}
+
+///
+/// A user defined type.
+///
+/// MySQL and SQLite only have enums. Domain, composite, and range types
+/// are only found on Postgres.
+///
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct TypeSchema {
     pub schema: String,
     pub type_name: String,
-    pub enum_value: String,
+    pub kind: TypeKind,
+
+    /// The labels of an enum, in their sort order.
+    /// Reordering these is a change.
+    pub enum_values: Vec<String>,
+
+    /// The type a domain is based on, or the subtype of a range.
+    pub base_type: Option<String>,
+
+    /// The default of a domain.
+    pub default: Option<String>,
+
+    /// The constraints of a domain, including `NOT NULL`.
+    /// i.e. `CHECK (VALUE > 0)`.
+    pub constraints: Vec<String>,
+
+    /// The attributes of a composite type, in order.
+    /// i.e. `street text`.
+    pub attributes: Vec<String>,
 }
 
 impl PartialOrd for TypeSchema {
@@ -24,12 +59,17 @@ impl Ord for TypeSchema {
     fn cmp(&self, other: &Self) -> Ordering {
         self.type_name
             .cmp(&other.type_name)
-            .then_with(|| self.enum_value.cmp(&other.enum_value))
             .then_with(|| self.schema.cmp(&other.schema))
+            .then_with(|| self.kind.cmp(&other.kind))
+            .then_with(|| self.enum_values.cmp(&other.enum_values))
+            .then_with(|| self.base_type.cmp(&other.base_type))
+            .then_with(|| self.default.cmp(&other.default))
+            .then_with(|| self.constraints.cmp(&other.constraints))
+            .then_with(|| self.attributes.cmp(&other.attributes))
     }
 }
 
-pub async fn get_type_schemas<C>(db_connection: &C) -> Vec<TypeSchema>
+pub async fn get_type_schemas<C>(db_connection: &C) -> Result<Vec<TypeSchema>, DbErr>
 where
     C: ConnectionTrait,
 {
@@ -37,32 +77,29 @@ where
     let sql = get_type_query_sql(db_backend);
     let list_tables_statement = Statement::from_string(db_backend, sql.to_string());
 
-    let table_results = db_connection
-        .query_all(list_tables_statement)
-        .await
-        .expect("expect results from listing tables");
+    let table_results = db_connection.query_all(list_tables_statement).await?;
 
-    let mut type_schemas = build_type_schema(db_backend, table_results);
+    let mut type_schemas = build_type_schema(db_backend, table_results)?;
     type_schemas.sort();
 
-    type_schemas
+    Ok(type_schemas)
 }
 
 fn build_type_schema(
     db_backend: DatabaseBackend,
     table_results: Vec<QueryResult>,
-) -> Vec<TypeSchema> {
+) -> Result<Vec<TypeSchema>, DbErr> {
     match db_backend {
-        DatabaseBackend::MySql => unimplemented!("MySql support is not yet implemented"),
+        DatabaseBackend::MySql => mysql::build_type_schema(table_results),
         DatabaseBackend::Postgres => postgres::build_type_schema(table_results),
-        DatabaseBackend::Sqlite => unimplemented!("Sqlite support is not yet implemented"),
+        DatabaseBackend::Sqlite => sqlite::build_type_schema(table_results),
     }
 }
 
 fn get_type_query_sql(db_backend: DatabaseBackend) -> &'static str {
     match db_backend {
-        DatabaseBackend::MySql => unimplemented!("MySql support is not yet implemented"),
+        DatabaseBackend::MySql => mysql::QUERY_TYPE_SCHEMA_SQL,
         DatabaseBackend::Postgres => postgres::QUERY_TYPE_SCHEMA_SQL,
-        DatabaseBackend::Sqlite => unimplemented!("Sqlite support is not yet implemented"),
+        DatabaseBackend::Sqlite => sqlite::QUERY_TYPE_SCHEMA_SQL,
     }
 }
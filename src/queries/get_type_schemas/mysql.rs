@@ -0,0 +1,75 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::TypeKind;
+use super::TypeSchema;
+
+/*
+ * MySQL has no named types. Instead `ENUM` and `SET` types are declared
+ * inline on each column. These are returned using `table.column` as the
+ * type name.
+ *
+ * Only the current database is read, so the schema is left empty.
+ */
+pub const QUERY_TYPE_SCHEMA_SQL: &str = r#"
+    SELECT
+        CAST(CONCAT(TABLE_NAME, '.', COLUMN_NAME) AS CHAR) AS type_name,
+        CAST(COLUMN_TYPE AS CHAR) AS column_type
+    FROM information_schema.COLUMNS
+    WHERE TABLE_SCHEMA = DATABASE()
+    AND DATA_TYPE IN ('enum', 'set')
+    ORDER BY TABLE_NAME, COLUMN_NAME
+"#;
+
+pub fn build_type_schema(type_results: Vec<QueryResult>) -> Result<Vec<TypeSchema>, DbErr> {
+    type_results
+        .into_iter()
+        .map(|type_result| {
+            let type_name = type_result.try_get::<String>("", "type_name")?;
+
+            let column_type = type_result.try_get::<String>("", "column_type")?;
+
+            Ok(TypeSchema {
+                schema: String::new(),
+                type_name,
+                kind: TypeKind::Enum,
+                enum_values: parse_enum_values(&column_type),
+                base_type: None,
+                default: None,
+                constraints: Vec::new(),
+                attributes: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+///
+/// Pulls the values out of a column type such as `enum('a','b')`.
+///
+fn parse_enum_values(column_type: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut chars = column_type.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\'' {
+            continue;
+        }
+
+        let mut value = String::new();
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('\'', Some('\'')) => {
+                    chars.next();
+                    value.push('\'');
+                }
+                ('\'', _) => break,
+                ('\\', Some(_)) => value.extend(chars.next()),
+                (c, _) => value.push(c),
+            }
+        }
+
+        values.push(value);
+    }
+
+    values
+}
@@ -1,40 +1,99 @@
+use ::sea_orm_migration::sea_orm::DbErr;
 use ::sea_orm_migration::sea_orm::QueryResult;
 
+use super::TypeKind;
 use super::TypeSchema;
 
-pub const QUERY_TYPE_SCHEMA_SQL: &'static str = &r#"
+/*
+ * Composite types are backed by a `pg_class` row. Every table also has one
+ * of these, so only those with a `relkind` of 'c' are standalone types.
+ *
+ * A domain set `NOT NULL` is stored on the type, rather than as a constraint.
+ */
+pub const QUERY_TYPE_SCHEMA_SQL: &str = r#"
     SELECT
         n.nspname AS schema,
         t.typname AS type_name,
-        e.enumlabel AS enum_value
-    FROM pg_type t
-    JOIN pg_enum e ON t.oid = e.enumtypid
+        t.typtype::text AS type_kind,
+        ARRAY(
+            SELECT e.enumlabel::text
+            FROM pg_catalog.pg_enum e
+            WHERE e.enumtypid = t.oid
+            ORDER BY e.enumsortorder
+        ) AS enum_values,
+        CASE t.typtype
+            WHEN 'd' THEN pg_catalog.format_type(t.typbasetype, t.typtypmod)
+            WHEN 'r' THEN pg_catalog.format_type(r.rngsubtype, NULL)
+        END AS base_type,
+        t.typdefault AS default_value,
+        ARRAY(
+            SELECT pg_catalog.pg_get_constraintdef(con.oid, true)
+            FROM pg_catalog.pg_constraint con
+            WHERE con.contypid = t.oid
+            AND con.contype = 'c'
+            ORDER BY con.conname
+        ) || CASE WHEN t.typnotnull
+            THEN ARRAY['NOT NULL']
+            ELSE ARRAY[]::text[]
+        END AS constraints,
+        ARRAY(
+            SELECT a.attname || ' ' || pg_catalog.format_type(a.atttypid, a.atttypmod)
+            FROM pg_catalog.pg_attribute a
+            WHERE a.attrelid = t.typrelid
+            AND a.attnum > 0
+            AND NOT a.attisdropped
+            ORDER BY a.attnum
+        ) AS attributes
+    FROM pg_catalog.pg_type t
     JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
-    WHERE t.typtype = 'e'
-    ORDER BY t.typname, e.enumsortorder;
+    LEFT JOIN pg_catalog.pg_class c ON c.oid = t.typrelid
+    LEFT JOIN pg_catalog.pg_range r ON r.rngtypid = t.oid
+    WHERE (t.typtype IN ('e', 'd', 'r') OR (t.typtype = 'c' AND c.relkind = 'c'))
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_depend ext
+        WHERE ext.classid = 'pg_catalog.pg_type'::regclass
+        AND ext.objid = t.oid
+        AND ext.deptype = 'e'
+    )
+    ORDER BY n.nspname, t.typname
 "#;
 
-pub fn build_type_schema(table_results: Vec<QueryResult>) -> Vec<TypeSchema> {
-    table_results
+pub fn build_type_schema(type_results: Vec<QueryResult>) -> Result<Vec<TypeSchema>, DbErr> {
+    type_results
         .into_iter()
         .map(|type_result| {
-            let schema = type_result
-                .try_get::<String>("", "schema")
-                .expect("expect `schema` to be present in SQL Query results");
+            let schema = type_result.try_get::<String>("", "schema")?;
+            let type_name = type_result.try_get::<String>("", "type_name")?;
+            let type_kind = type_result.try_get::<String>("", "type_kind")?;
+            let enum_values = type_result.try_get::<Vec<String>>("", "enum_values")?;
+            let base_type = type_result.try_get::<Option<String>>("", "base_type")?;
+            let default = type_result.try_get::<Option<String>>("", "default_value")?;
+            let constraints = type_result.try_get::<Vec<String>>("", "constraints")?;
+            let attributes = type_result.try_get::<Vec<String>>("", "attributes")?;
 
-            let type_name = type_result
-                .try_get::<String>("", "type_name")
-                .expect("expect `type_name` to be present in SQL Query results");
-
-            let enum_value = type_result
-                .try_get::<String>("", "enum_value")
-                .expect("expect `enum_value` to be present in SQL Query results");
-
-            TypeSchema {
+            Ok(TypeSchema {
                 schema,
                 type_name,
-                enum_value,
-            }
+                kind: to_type_kind(&type_kind)?,
+                enum_values,
+                base_type,
+                default,
+                constraints,
+                attributes,
+            })
         })
         .collect()
 }
+
+fn to_type_kind(type_kind: &str) -> Result<TypeKind, DbErr> {
+    let kind = match type_kind {
+        "e" => TypeKind::Enum,
+        "d" => TypeKind::Domain,
+        "c" => TypeKind::Composite,
+        "r" => TypeKind::Range,
+        _ => return Err(DbErr::Custom(format!("unknown type kind `{type_kind}`"))),
+    };
+
+    Ok(kind)
+}
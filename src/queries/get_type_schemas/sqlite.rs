@@ -0,0 +1,85 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::TypeKind;
+use super::TypeSchema;
+use crate::queries::sqlite_sql::split_table_definitions;
+use crate::queries::sqlite_sql::tokenize_sql;
+use crate::queries::sqlite_sql::unquote_identifier;
+
+/*
+ * SQLite has no enum types. The closest it has are `CHECK` constraints
+ * limiting a column to a list of values, which is what Sea Query emits.
+ * i.e. `CHECK ("rating" IN ('good', 'bad'))`.
+ *
+ * These are returned using `table.column` as the type name.
+ */
+pub const QUERY_TYPE_SCHEMA_SQL: &str = r#"
+    SELECT name as table_name, sql as table_sql
+        FROM sqlite_master
+    WHERE
+        type = "table" AND
+        name != "seaql_migrations" AND
+        name != "sqlite_sequence"
+"#;
+
+pub fn build_type_schema(type_results: Vec<QueryResult>) -> Result<Vec<TypeSchema>, DbErr> {
+    let mut all_type_schemas = Vec::new();
+
+    for type_result in type_results {
+        let table_name = type_result.try_get::<String>("", "table_name")?;
+        let table_sql = type_result.try_get::<String>("", "table_sql")?;
+
+        all_type_schemas.extend(parse_check_enums(&table_sql).into_iter().map(
+            |(column_name, enum_values)| TypeSchema {
+                schema: "main".to_string(),
+                type_name: format!("{}.{}", table_name, column_name),
+                kind: TypeKind::Enum,
+                enum_values,
+                base_type: None,
+                default: None,
+                constraints: Vec::new(),
+                attributes: Vec::new(),
+            },
+        ));
+    }
+
+    Ok(all_type_schemas)
+}
+
+///
+/// Finds all `CHECK (column IN (values))` constraints in a `CREATE TABLE`.
+/// Returning each column, and it's values in the order they are written.
+///
+fn parse_check_enums(table_sql: &str) -> Vec<(String, Vec<String>)> {
+    let mut check_enums = Vec::new();
+
+    for definition in split_table_definitions(table_sql) {
+        let check_expressions = definition
+            .windows(2)
+            .filter(|tokens| tokens[0].eq_ignore_ascii_case("CHECK"))
+            .filter_map(|tokens| tokens[1].strip_prefix('(')?.strip_suffix(')'));
+
+        for check_expression in check_expressions {
+            let tokens = tokenize_sql(check_expression);
+            let [column, in_keyword, values] = tokens[..] else {
+                continue;
+            };
+            if !in_keyword.eq_ignore_ascii_case("IN") {
+                continue;
+            }
+            let Some(values) = values.strip_prefix('(').and_then(|v| v.strip_suffix(')')) else {
+                continue;
+            };
+
+            let enum_values = tokenize_sql(values)
+                .into_iter()
+                .filter_map(|value| value.strip_prefix('\'')?.strip_suffix('\''))
+                .map(|value| value.replace("''", "'"))
+                .collect();
+            check_enums.push((unquote_identifier(column), enum_values));
+        }
+    }
+
+    check_enums
+}
@@ -0,0 +1,59 @@
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DatabaseBackend;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+use ::sea_orm_migration::sea_orm::Statement;
+
+mod postgres;
+mod sqlite;
+
+///
+/// A view, or materialized view, and the query behind it.
+///
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct ViewSchema {
+    pub schema: String,
+    pub name: String,
+
+    /// The SQL defining the view, with whitespace normalised.
+    pub definition: String,
+
+    pub is_materialized: bool,
+}
+
+pub async fn get_view_schemas<C>(db_connection: &C) -> Result<Vec<ViewSchema>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let db_backend = db_connection.get_database_backend();
+    let Some(sql) = get_view_query_sql(db_backend) else {
+        return Ok(Vec::new());
+    };
+    let list_views_statement = Statement::from_string(db_backend, sql.to_string());
+
+    let view_results = db_connection.query_all(list_views_statement).await?;
+
+    let mut view_schemas = build_view_schema(db_backend, view_results)?;
+    view_schemas.sort();
+
+    Ok(view_schemas)
+}
+
+fn build_view_schema(
+    db_backend: DatabaseBackend,
+    view_results: Vec<QueryResult>,
+) -> Result<Vec<ViewSchema>, DbErr> {
+    match db_backend {
+        DatabaseBackend::MySql => Ok(Vec::new()),
+        DatabaseBackend::Postgres => postgres::build_view_schema(view_results),
+        DatabaseBackend::Sqlite => sqlite::build_view_schema(view_results),
+    }
+}
+
+fn get_view_query_sql(db_backend: DatabaseBackend) -> Option<&'static str> {
+    match db_backend {
+        DatabaseBackend::MySql => None,
+        DatabaseBackend::Postgres => Some(postgres::QUERY_VIEW_SCHEMA_SQL),
+        DatabaseBackend::Sqlite => Some(sqlite::QUERY_VIEW_SCHEMA_SQL),
+    }
+}
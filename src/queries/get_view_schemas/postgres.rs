@@ -0,0 +1,43 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::ViewSchema;
+use crate::queries::sqlite_sql::normalise_sql;
+
+pub const QUERY_VIEW_SCHEMA_SQL: &str = r#"
+    SELECT
+        n.nspname AS schema_name,
+        c.relname AS view_name,
+        pg_catalog.pg_get_viewdef(c.oid, true) AS view_sql,
+        c.relkind = 'm' AS is_materialized
+    FROM pg_catalog.pg_class c
+    JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+    WHERE c.relkind IN ('v', 'm')
+    AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND NOT EXISTS (
+        SELECT 1 FROM pg_catalog.pg_depend ext
+        WHERE ext.classid = 'pg_catalog.pg_class'::regclass
+        AND ext.objid = c.oid
+        AND ext.deptype = 'e'
+    )
+    ORDER BY n.nspname, c.relname
+"#;
+
+pub fn build_view_schema(view_results: Vec<QueryResult>) -> Result<Vec<ViewSchema>, DbErr> {
+    view_results
+        .into_iter()
+        .map(|view_result| {
+            let schema = view_result.try_get::<String>("", "schema_name")?;
+            let name = view_result.try_get::<String>("", "view_name")?;
+            let view_sql = view_result.try_get::<String>("", "view_sql")?;
+            let is_materialized = view_result.try_get::<bool>("", "is_materialized")?;
+
+            Ok(ViewSchema {
+                schema,
+                name,
+                definition: normalise_sql(&view_sql),
+                is_materialized,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,28 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::QueryResult;
+
+use super::ViewSchema;
+use crate::queries::sqlite_sql::normalise_sql;
+
+pub const QUERY_VIEW_SCHEMA_SQL: &str = r#"
+    SELECT name as view_name, sql as view_sql
+        FROM sqlite_master
+    WHERE type = "view"
+"#;
+
+pub fn build_view_schema(view_results: Vec<QueryResult>) -> Result<Vec<ViewSchema>, DbErr> {
+    view_results
+        .into_iter()
+        .map(|view_result| {
+            let name = view_result.try_get::<String>("", "view_name")?;
+            let view_sql = view_result.try_get::<String>("", "view_sql")?;
+
+            Ok(ViewSchema {
+                schema: "main".to_string(),
+                name,
+                definition: normalise_sql(&view_sql),
+                is_materialized: false,
+            })
+        })
+        .collect()
+}
@@ -1,8 +1,46 @@
+mod get_constraint_schemas;
+pub use self::get_constraint_schemas::*;
+
+mod get_extension_schemas;
+pub use self::get_extension_schemas::*;
+
+mod get_function_schemas;
+pub use self::get_function_schemas::*;
+
+mod get_index_schemas;
+pub use self::get_index_schemas::*;
+
+mod get_namespace_schemas;
+pub use self::get_namespace_schemas::*;
+
+mod get_policy_schemas;
+pub use self::get_policy_schemas::*;
+
+mod get_privilege_schemas;
+pub use self::get_privilege_schemas::*;
+
+mod get_row_security_schemas;
+pub use self::get_row_security_schemas::*;
+
+mod get_schema_snapshot;
+pub use self::get_schema_snapshot::*;
+
+mod get_sequence_schemas;
+pub use self::get_sequence_schemas::*;
+
 mod get_table_schemas;
 pub use self::get_table_schemas::*;
 
+mod get_trigger_schemas;
+pub use self::get_trigger_schemas::*;
+
 mod get_type_schemas;
 pub use self::get_type_schemas::*;
 
+mod get_view_schemas;
+pub use self::get_view_schemas::*;
+
 mod new_test_db_connection;
 pub use self::new_test_db_connection::*;
+
+mod sqlite_sql;
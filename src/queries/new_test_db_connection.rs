@@ -1,10 +1,7 @@
 use ::sea_orm_migration::sea_orm::Database;
 use ::sea_orm_migration::sea_orm::DatabaseConnection;
+use ::sea_orm_migration::sea_orm::DbErr;
 
-pub async fn new_test_db_connection(db_url: &str) -> DatabaseConnection {
-    let db_connection = Database::connect(db_url)
-        .await
-        .expect("expect temporary DB connection to be created");
-
-    db_connection
+pub async fn new_test_db_connection(db_url: &str) -> Result<DatabaseConnection, DbErr> {
+    Database::connect(db_url).await
 }
@@ -0,0 +1,187 @@
+use ::std::iter::Peekable;
+
+/*
+ * Helpers for pulling apart the SQL SQLite stores in `sqlite_master`.
+ *
+ * SQLite does not expose everything through it's pragmas. i.e. collations,
+ * `AUTOINCREMENT`, and generated column expressions. These are only found
+ * in the original `CREATE TABLE` SQL.
+ */
+
+///
+/// Splits SQL into tokens.
+///
+/// Quoted strings and identifiers are kept whole, as are bracketed groups.
+/// i.e. `CHECK ("a" IN ('x', 'y'))` becomes `CHECK`, and `("a" IN ('x', 'y'))`.
+///
+pub fn tokenize_sql(sql: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let end = match c {
+            c if c.is_whitespace() => continue,
+            '\'' | '"' | '`' | '[' => {
+                let close = if c == '[' { ']' } else { c };
+                find_quote_end(&mut chars, close)
+            }
+            '(' => find_bracket_end(&mut chars),
+            c if is_word_char(c) => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, next)) = chars.peek() {
+                    if !is_word_char(next) {
+                        break;
+                    }
+                    end = i + next.len_utf8();
+                    chars.next();
+                }
+                end
+            }
+            c => start + c.len_utf8(),
+        };
+
+        tokens.push(&sql[start..end.min(sql.len())]);
+    }
+
+    tokens
+}
+
+///
+/// Returns each column definition, and table constraint, within a
+/// `CREATE TABLE` statement. Each is returned as a list of tokens.
+///
+pub fn split_table_definitions(table_sql: &str) -> Vec<Vec<&str>> {
+    let maybe_body = tokenize_sql(table_sql)
+        .into_iter()
+        .find_map(|token| token.strip_prefix('(')?.strip_suffix(')'));
+
+    let Some(body) = maybe_body else {
+        return Vec::new();
+    };
+
+    tokenize_sql(body)
+        .split(|token| *token == ",")
+        .filter(|definition| !definition.is_empty())
+        .map(|definition| definition.to_vec())
+        .collect()
+}
+
+///
+/// Returns true if this definition, from `split_table_definitions`,
+/// is a table constraint rather than a column.
+///
+pub fn is_table_constraint(definition: &[&str]) -> bool {
+    definition.first().is_some_and(|token| {
+        ["CONSTRAINT", "PRIMARY", "UNIQUE", "CHECK", "FOREIGN"]
+            .iter()
+            .any(|keyword| token.eq_ignore_ascii_case(keyword))
+    })
+}
+
+///
+/// Removes the quotes from around an identifier, if it has any.
+///
+pub fn unquote_identifier(token: &str) -> String {
+    let mut chars = token.chars();
+    match (chars.next(), chars.next_back()) {
+        (Some(open @ ('"' | '`')), Some(close)) if open == close => {
+            let quote = open.to_string();
+            chars.as_str().replace(&quote.repeat(2), &quote)
+        }
+        (Some('['), Some(']')) => chars.as_str().to_string(),
+        _ => token.to_string(),
+    }
+}
+
+///
+/// Collapses whitespace outside of quotes, so SQL written with different
+/// formatting compares as equal.
+///
+/// This is also used for the view definitions Postgres returns,
+/// which are spread over multiple lines.
+///
+pub fn normalise_sql(sql: &str) -> String {
+    let mut normalised = String::with_capacity(sql.len());
+    let mut quote: Option<char> = None;
+    let mut has_pending_space = false;
+
+    for c in sql.chars() {
+        if let Some(close) = quote {
+            normalised.push(c);
+            if c == close {
+                quote = None;
+            }
+            continue;
+        }
+
+        if c.is_whitespace() {
+            has_pending_space = !normalised.is_empty();
+            continue;
+        }
+
+        if has_pending_space && !normalised.ends_with('(') && c != ')' && c != ',' {
+            normalised.push(' ');
+        }
+        has_pending_space = false;
+
+        quote = match c {
+            '\'' | '"' | '`' => Some(c),
+            '[' => Some(']'),
+            _ => None,
+        };
+        normalised.push(c);
+    }
+
+    normalised
+}
+
+fn find_quote_end<I>(chars: &mut Peekable<I>, close: char) -> usize
+where
+    I: Iterator<Item = (usize, char)>,
+{
+    while let Some((i, c)) = chars.next() {
+        if c == close {
+            // A doubled quote is an escaped quote, and so not the end.
+            match chars.peek() {
+                Some(&(_, next)) if next == close && close != ']' => {
+                    chars.next();
+                }
+                _ => return i + c.len_utf8(),
+            }
+        }
+    }
+
+    usize::MAX
+}
+
+fn find_bracket_end<I>(chars: &mut Peekable<I>) -> usize
+where
+    I: Iterator<Item = (usize, char)>,
+{
+    let mut depth = 1;
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\'' | '"' | '`' => {
+                find_quote_end(chars, c);
+            }
+            '[' => {
+                find_quote_end(chars, ']');
+            }
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + c.len_utf8();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    usize::MAX
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$' || c == '.'
+}
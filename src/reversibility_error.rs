@@ -0,0 +1,137 @@
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::std::error::Error;
+use ::std::fmt::Display;
+use ::std::fmt::Formatter;
+use ::std::fmt::Result as FmtResult;
+
+///
+/// An error which stopped migrations from being checked.
+///
+/// This is different to finding a migration which is not reversible,
+/// which is described with a `ReversibilityReport`.
+///
+#[derive(Debug)]
+pub enum ReversibilityError {
+    /// Connecting to the database failed.
+    Connection(DbErr),
+
+    /// Running a migration up failed.
+    Up {
+        index: usize,
+        name: String,
+        source: DbErr,
+    },
+
+    /// Running a migration down failed.
+    Down {
+        index: usize,
+        name: String,
+        source: DbErr,
+    },
+
+    /// Seeding a fixture failed, after the migration was run up.
+    Fixture {
+        index: usize,
+        name: String,
+        source: DbErr,
+    },
+
+    /// Reading the database schema failed, around running a migration.
+    Introspection {
+        index: usize,
+        name: String,
+        stage: SnapshotStage,
+        source: DbErr,
+    },
+
+    /// No migration has the name given to `ReversibilityOptions::with_start_name`,
+    /// or to `ReversibilityOptions::with_irreversible_migrations`.
+    UnknownMigration(String),
+}
+
+impl Display for ReversibilityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Connection(source) => {
+                write!(f, "Failed to connect to the database: {}", source)
+            }
+            Self::Up {
+                index,
+                name,
+                source,
+            } => write!(
+                f,
+                "Migration at index {} ({}) failed running up: {}",
+                index, name, source
+            ),
+            Self::Down {
+                index,
+                name,
+                source,
+            } => write!(
+                f,
+                "Migration at index {} ({}) failed running down: {}",
+                index, name, source
+            ),
+            Self::Fixture {
+                index,
+                name,
+                source,
+            } => write!(
+                f,
+                "Fixture for migration at index {} ({}) failed to seed: {}",
+                index, name, source
+            ),
+            Self::Introspection {
+                index,
+                name,
+                stage,
+                source,
+            } => write!(
+                f,
+                "Failed to read the database schema {} migration at index {} ({}): {}",
+                stage, index, name, source
+            ),
+            Self::UnknownMigration(name) => {
+                write!(f, "No migration found with the name {}", name)
+            }
+        }
+    }
+}
+
+impl Error for ReversibilityError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Connection(source) => Some(source),
+            Self::Up { source, .. }
+            | Self::Down { source, .. }
+            | Self::Fixture { source, .. }
+            | Self::Introspection { source, .. } => Some(source),
+            Self::UnknownMigration(_) => None,
+        }
+    }
+}
+
+///
+/// When the database schema was being read, relative to running a migration.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotStage {
+    BeforeUp,
+    AfterUp,
+    AfterDown,
+    AfterUpAgain,
+}
+
+impl Display for SnapshotStage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let stage = match self {
+            Self::BeforeUp => "before running up",
+            Self::AfterUp => "after running up",
+            Self::AfterDown => "after running down",
+            Self::AfterUpAgain => "after running up again",
+        };
+
+        write!(f, "{}", stage)
+    }
+}
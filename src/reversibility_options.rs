@@ -0,0 +1,211 @@
+use ::std::sync::Arc;
+
+use crate::MigrationFixture;
+use crate::RegisteredFixture;
+
+///
+/// Options for changing what is checked, when testing if migrations are reversible.
+///
+/// ```rust
+/// use ::assert_migrator_reversible::ReversibilityOptions;
+///
+/// let options = ReversibilityOptions::new().with_schemas(["public", "audit"]);
+/// ```
+///
+#[derive(Debug, Clone, Default)]
+pub struct ReversibilityOptions {
+    pub(crate) check_mode: CheckMode,
+    pub(crate) start: CheckStart,
+    pub(crate) schemas: Option<Vec<String>>,
+    pub(crate) is_checking_security: bool,
+    pub(crate) is_checking_comments: bool,
+    pub(crate) fixtures: Vec<RegisteredFixture>,
+    pub(crate) irreversible_migrations: Vec<String>,
+}
+
+impl ReversibilityOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// How migrations are run when checking them.
+    ///
+    /// By default this is `CheckMode::UpThenDown`.
+    ///
+    pub fn with_check_mode(mut self, check_mode: CheckMode) -> Self {
+        self.check_mode = check_mode;
+        self
+    }
+
+    ///
+    /// Only migrations from this index onwards are checked.
+    /// Migrations before it are run up, but not checked.
+    ///
+    /// This replaces any earlier `with_start_name`, or `with_last_migrations`.
+    ///
+    pub fn with_start_index(mut self, start_index: usize) -> Self {
+        self.start = CheckStart::Index(start_index);
+        self
+    }
+
+    ///
+    /// Only migrations from the one with this name onwards are checked.
+    /// Migrations before it are run up, but not checked.
+    ///
+    /// If no migration has this name, a `ReversibilityError::UnknownMigration`
+    /// is returned.
+    ///
+    /// This replaces any earlier `with_start_index`, or `with_last_migrations`.
+    ///
+    pub fn with_start_name<S>(mut self, start_name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.start = CheckStart::Name(start_name.into());
+        self
+    }
+
+    ///
+    /// Only the last `count` migrations are checked.
+    /// Migrations before them are run up, but not checked.
+    ///
+    /// This replaces any earlier `with_start_index`, or `with_start_name`.
+    ///
+    pub fn with_last_migrations(mut self, count: usize) -> Self {
+        self.start = CheckStart::Last(count);
+        self
+    }
+
+    ///
+    /// Only objects within these schemas will be compared.
+    /// Everything in any other schema is ignored.
+    ///
+    /// By default all schemas are compared. On MySQL objects have an empty
+    /// schema, as only the current database is read.
+    ///
+    pub fn with_schemas<I, S>(mut self, schemas: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.schemas = Some(schemas.into_iter().map(Into::into).collect());
+        self
+    }
+
+    ///
+    /// When true, row level security, policies, and privileges granted on
+    /// tables are also compared. This is only supported on Postgres.
+    ///
+    /// By default this is off.
+    ///
+    pub fn with_security(mut self, is_checking_security: bool) -> Self {
+        self.is_checking_security = is_checking_security;
+        self
+    }
+
+    ///
+    /// When true, comments on tables and columns are also compared.
+    /// This is only supported on Postgres.
+    ///
+    /// By default this is off, as comments do not change how the database behaves.
+    ///
+    pub fn with_comments(mut self, is_checking_comments: bool) -> Self {
+        self.is_checking_comments = is_checking_comments;
+        self
+    }
+
+    ///
+    /// Marks migrations, by name, as intentionally irreversible.
+    /// i.e. data backfills, or dropping a deprecated table.
+    ///
+    /// These are still run, but their differences are not reported.
+    /// Instead a report is returned if one of them turns out to be reversible.
+    ///
+    /// With `CheckMode::UpThenDown` the migrations up to the last irreversible
+    /// one are checked as with `CheckMode::RoundTrip`. As the migrations before
+    /// an irreversible migration cannot be compared against a schema it has changed.
+    ///
+    /// The `down` of an irreversible migration is still run, and should return
+    /// an error rather than panic.
+    /// A panic, such as from `todo!()`, is not caught and stops the check.
+    ///
+    /// If a name does not match any migration, a `ReversibilityError::UnknownMigration`
+    /// is returned.
+    ///
+    pub fn with_irreversible_migrations<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.irreversible_migrations
+            .extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    pub(crate) fn is_irreversible_migration(&self, name: &str) -> bool {
+        self.irreversible_migrations
+            .iter()
+            .any(|irreversible| irreversible == name)
+    }
+
+    ///
+    /// Adds a fixture, which is seeded after the migration at `migration_index`
+    /// has been run up. It is then verified after the next migration has been
+    /// run up and back down.
+    ///
+    /// With `CheckMode::UpThenDown` the next migration is checked as with
+    /// `CheckMode::RoundTrip`. So the fixture is verified before any later
+    /// migration is run, and data they lose is not blamed on it.
+    ///
+    /// Fixtures on the last migration are seeded, but never verified.
+    ///
+    pub fn with_fixture<F>(mut self, migration_index: usize, fixture: F) -> Self
+    where
+        F: MigrationFixture + 'static,
+    {
+        self.fixtures.push(RegisteredFixture {
+            migration_index,
+            fixture: Arc::new(fixture),
+        });
+        self
+    }
+}
+
+///
+/// How migrations are run, when checking if they are reversible.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CheckMode {
+    ///
+    /// Run every migration up, and then every migration back down.
+    /// Migrations are checked in reverse order.
+    ///
+    /// Migrations up to the last one given to `ReversibilityOptions::with_irreversible_migrations`,
+    /// and the migration after each `ReversibilityOptions::with_fixture`, are instead checked
+    /// as with `CheckMode::RoundTrip`.
+    ///
+    #[default]
+    UpThenDown,
+
+    ///
+    /// Run each migration up, down, and then up again, before moving on to the next.
+    /// Migrations are checked in order.
+    ///
+    /// This also finds migrations which fail, or produce a different schema,
+    /// when run up again after being run down.
+    ///
+    RoundTrip,
+}
+
+///
+/// Which migration checking starts from.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) enum CheckStart {
+    #[default]
+    First,
+    Index(usize),
+    Name(String),
+    Last(usize),
+}
@@ -0,0 +1,100 @@
+use ::std::fmt::Display;
+use ::std::fmt::Formatter;
+use ::std::fmt::Result as FmtResult;
+
+use crate::queries::SchemaSnapshot;
+use crate::SchemaDifference;
+
+///
+/// Describes a migration which was found to not be reversible.
+/// Or, one marked as irreversible which was found to be reversible.
+///
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ReversibilityReport {
+    /// The index of the migration, within the `Migrator`.
+    pub index: usize,
+
+    /// The name of the migration, as returned by `MigrationName::name()`.
+    pub name: String,
+
+    /// How the migration was found to not be reversible.
+    pub kind: NonReversibleKind,
+
+    /// The database schema before the migration was run up.
+    ///
+    /// For `NonReversibleKind::UpDiffersAfterDown` this is the schema
+    /// after the migration was first run up.
+    pub before: SchemaSnapshot,
+
+    /// The database schema after the migration was run back down.
+    ///
+    /// For `NonReversibleKind::UpDiffersAfterDown` this is the schema
+    /// after the migration was run up again.
+    pub after: SchemaSnapshot,
+
+    /// What differs between `before` and `after`.
+    pub differences: Vec<SchemaDifference>,
+}
+
+impl Display for ReversibilityReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.kind == NonReversibleKind::MarkedIrreversible {
+            return write!(
+                f,
+                "Migration at index {} ({}) is marked as irreversible, but is reversible",
+                self.index, self.name
+            );
+        }
+
+        write!(
+            f,
+            "Migration at index {} ({}) is not reversible",
+            self.index, self.name
+        )?;
+
+        match &self.kind {
+            NonReversibleKind::DownDiffers | NonReversibleKind::MarkedIrreversible => {}
+            NonReversibleKind::UpFailedAfterDown(error) => {
+                write!(f, ", running up again after down failed: {}", error)?;
+            }
+            NonReversibleKind::UpDiffersAfterDown => {
+                write!(f, ", running up again after down gave a different schema")?;
+            }
+            NonReversibleKind::DataLost(message) => {
+                write!(f, ", data was lost: {}", message)?;
+            }
+        }
+
+        for difference in &self.differences {
+            write!(f, "\n  - {}", difference)?;
+        }
+
+        Ok(())
+    }
+}
+
+///
+/// How a migration was found to not be reversible.
+///
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum NonReversibleKind {
+    /// The schema after running down differs to the schema before running up.
+    DownDiffers,
+
+    /// Running up again after running down returned an error.
+    /// This holds the error message.
+    UpFailedAfterDown(String),
+
+    /// Running up again after running down gave a different schema,
+    /// to when it was first run up.
+    UpDiffersAfterDown,
+
+    /// A fixture added for the previous migration failed to verify,
+    /// after this migration was run up and back down.
+    /// This holds the message from the fixture.
+    DataLost(String),
+
+    /// The migration was marked with `ReversibilityOptions::with_irreversible_migrations`,
+    /// but running it down gave the same schema as before it was run up.
+    MarkedIrreversible,
+}
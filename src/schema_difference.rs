@@ -0,0 +1,334 @@
+use ::std::collections::BTreeMap;
+use ::std::fmt::Display;
+use ::std::fmt::Formatter;
+use ::std::fmt::Result as FmtResult;
+
+use crate::queries::ConstraintSchema;
+use crate::queries::ExtensionSchema;
+use crate::queries::FunctionSchema;
+use crate::queries::IndexSchema;
+use crate::queries::NamespaceSchema;
+use crate::queries::PolicySchema;
+use crate::queries::PrivilegeSchema;
+use crate::queries::RowSecuritySchema;
+use crate::queries::SchemaSnapshot;
+use crate::queries::SequenceSchema;
+use crate::queries::TableSchema;
+use crate::queries::TriggerSchema;
+use crate::queries::TypeSchema;
+use crate::queries::ViewSchema;
+
+mod constraint_schema;
+mod describe_part;
+mod extension_schema;
+mod function_schema;
+mod index_schema;
+mod namespace_schema;
+mod policy_schema;
+mod privilege_schema;
+mod row_security_schema;
+mod sequence_schema;
+mod table_schema;
+mod trigger_schema;
+mod type_schema;
+mod view_schema;
+
+pub(crate) use self::describe_part::*;
+
+/// The default schemas on Postgres, SQLite, and MySQL.
+/// MySQL leaves the schema empty, as only the current database is read.
+const DEFAULT_SCHEMAS: [&str; 3] = ["public", "main", ""];
+
+///
+/// A single item found in a database schema, which can be compared.
+///
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SchemaObject {
+    Namespace(NamespaceSchema),
+    Extension(ExtensionSchema),
+    Table(TableSchema),
+    Type(TypeSchema),
+    Index(IndexSchema),
+    Constraint(ConstraintSchema),
+    Sequence(SequenceSchema),
+    View(ViewSchema),
+    Trigger(TriggerSchema),
+    Function(FunctionSchema),
+    RowSecurity(RowSecuritySchema),
+    Policy(PolicySchema),
+    Privilege(PrivilegeSchema),
+}
+
+impl SchemaObject {
+    ///
+    /// A human readable name for what kind of object this is.
+    /// i.e. `table`.
+    ///
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Namespace(_) => "schema",
+            Self::Extension(_) => "extension",
+            Self::Table(_) => "table",
+            Self::Type(_) => "type",
+            Self::Index(_) => "index",
+            Self::Constraint(_) => "constraint",
+            Self::Sequence(_) => "sequence",
+            Self::View(_) => "view",
+            Self::Trigger(_) => "trigger",
+            Self::Function(function) if function.is_procedure => "procedure",
+            Self::Function(_) => "function",
+            Self::RowSecurity(_) => "row level security",
+            Self::Policy(_) => "policy",
+            Self::Privilege(_) => "privilege",
+        }
+    }
+
+    ///
+    /// The name of this object, as it would be shown in messages.
+    ///
+    pub fn name(&self) -> String {
+        match self {
+            Self::Namespace(namespace) => namespace.name.clone(),
+            Self::Extension(extension) => extension.name.clone(),
+            Self::Table(table) => qualify_name(&table.schema, &table.name),
+            Self::Type(type_schema) => qualify_name(&type_schema.schema, &type_schema.type_name),
+            Self::Index(index) => qualify_name(
+                &index.schema,
+                &format!("{}.{}", index.table_name, index.name),
+            ),
+            Self::Constraint(constraint) => qualify_name(
+                &constraint.schema,
+                &format!("{}.{}", constraint.table_name, constraint.name),
+            ),
+            Self::Sequence(sequence) => qualify_name(&sequence.schema, &sequence.name),
+            Self::View(view) => qualify_name(&view.schema, &view.name),
+            Self::Trigger(trigger) => qualify_name(
+                &trigger.schema,
+                &format!("{}.{}", trigger.table_name, trigger.name),
+            ),
+            Self::Function(function) => qualify_name(
+                &function.schema,
+                &format!("{}({})", function.name, function.arguments),
+            ),
+            Self::RowSecurity(row_security) => {
+                qualify_name(&row_security.schema, &row_security.table_name)
+            }
+            Self::Policy(policy) => qualify_name(
+                &policy.schema,
+                &format!("{}.{}", policy.table_name, policy.name),
+            ),
+            Self::Privilege(privilege) => {
+                let table_name = match &privilege.column_name {
+                    Some(column_name) => format!("{}.{}", privilege.table_name, column_name),
+                    None => privilege.table_name.clone(),
+                };
+
+                format!(
+                    "{} on {} to {}",
+                    privilege.privilege,
+                    qualify_name(&privilege.schema, &table_name),
+                    privilege.grantee
+                )
+            }
+        }
+    }
+}
+
+///
+/// Objects within the default schema are shown without it, to keep messages short.
+/// i.e. `book` rather than `public.book`.
+///
+fn qualify_name(schema: &str, name: &str) -> String {
+    if DEFAULT_SCHEMAS.contains(&schema) {
+        name.to_string()
+    } else {
+        format!("{}.{}", schema, name)
+    }
+}
+
+///
+/// A difference found between the database before a migration was run up,
+/// and after it was run back down.
+///
+/// `Added` means the object exists after going down, when it did not before.
+/// `Removed` means the object existed before, and is now missing.
+/// `Changed` is given once for each change found within the same object.
+///
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SchemaDifference {
+    Added(SchemaObject),
+    Removed(SchemaObject),
+    Changed {
+        before: Box<SchemaObject>,
+        after: Box<SchemaObject>,
+
+        /// A human readable description of what changed.
+        /// i.e. "gained column `name` (varchar, NOT NULL)".
+        change: String,
+    },
+}
+
+impl Display for SchemaDifference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Added(object) => write!(f, "{} `{}` was added", object.kind(), object.name()),
+            Self::Removed(object) => {
+                write!(f, "{} `{}` was removed", object.kind(), object.name())
+            }
+            Self::Changed { after, change, .. } => {
+                write!(f, "{} `{}` {}", after.kind(), after.name(), change)
+            }
+        }
+    }
+}
+
+///
+/// Objects are matched up between snapshots using their key.
+/// Objects with the same key, but otherwise differ, are reported as changed.
+///
+pub(crate) trait SchemaObjectDiff {
+    type Key: Ord;
+
+    fn key(&self) -> Self::Key;
+
+    ///
+    /// Describes each change going from `self` to `after`.
+    ///
+    /// If this returns nothing, a generic change is reported instead.
+    ///
+    fn describe_changes(&self, _after: &Self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+///
+/// Returns all of the differences found, going from `before` to `after`.
+///
+pub fn diff_schema_snapshots(
+    before: &SchemaSnapshot,
+    after: &SchemaSnapshot,
+) -> Vec<SchemaDifference> {
+    let mut differences = Vec::new();
+
+    diff_schema_objects(
+        &before.namespaces,
+        &after.namespaces,
+        SchemaObject::Namespace,
+        &mut differences,
+    );
+    diff_schema_objects(
+        &before.extensions,
+        &after.extensions,
+        SchemaObject::Extension,
+        &mut differences,
+    );
+    diff_schema_objects(
+        &before.tables,
+        &after.tables,
+        SchemaObject::Table,
+        &mut differences,
+    );
+    diff_schema_objects(
+        &before.types,
+        &after.types,
+        SchemaObject::Type,
+        &mut differences,
+    );
+    diff_schema_objects(
+        &before.indexes,
+        &after.indexes,
+        SchemaObject::Index,
+        &mut differences,
+    );
+    diff_schema_objects(
+        &before.constraints,
+        &after.constraints,
+        SchemaObject::Constraint,
+        &mut differences,
+    );
+    diff_schema_objects(
+        &before.sequences,
+        &after.sequences,
+        SchemaObject::Sequence,
+        &mut differences,
+    );
+    diff_schema_objects(
+        &before.views,
+        &after.views,
+        SchemaObject::View,
+        &mut differences,
+    );
+    diff_schema_objects(
+        &before.triggers,
+        &after.triggers,
+        SchemaObject::Trigger,
+        &mut differences,
+    );
+    diff_schema_objects(
+        &before.functions,
+        &after.functions,
+        SchemaObject::Function,
+        &mut differences,
+    );
+    diff_schema_objects(
+        &before.row_securities,
+        &after.row_securities,
+        SchemaObject::RowSecurity,
+        &mut differences,
+    );
+    diff_schema_objects(
+        &before.policies,
+        &after.policies,
+        SchemaObject::Policy,
+        &mut differences,
+    );
+    diff_schema_objects(
+        &before.privileges,
+        &after.privileges,
+        SchemaObject::Privilege,
+        &mut differences,
+    );
+
+    differences
+}
+
+fn diff_schema_objects<T>(
+    before: &[T],
+    after: &[T],
+    to_schema_object: fn(T) -> SchemaObject,
+    differences: &mut Vec<SchemaDifference>,
+) where
+    T: SchemaObjectDiff + PartialEq + Clone,
+{
+    let mut after_by_key: BTreeMap<T::Key, &T> =
+        after.iter().map(|object| (object.key(), object)).collect();
+
+    for before_object in before {
+        match after_by_key.remove(&before_object.key()) {
+            None => differences.push(SchemaDifference::Removed(to_schema_object(
+                before_object.clone(),
+            ))),
+            Some(after_object) if after_object != before_object => {
+                let mut changes = before_object.describe_changes(after_object);
+                if changes.is_empty() {
+                    changes.push("was changed".to_string());
+                }
+
+                for change in changes {
+                    differences.push(SchemaDifference::Changed {
+                        before: Box::new(to_schema_object(before_object.clone())),
+                        after: Box::new(to_schema_object(after_object.clone())),
+                        change,
+                    });
+                }
+            }
+            Some(_) => {}
+        }
+    }
+
+    for after_object in after_by_key.into_values() {
+        differences.push(SchemaDifference::Added(to_schema_object(
+            after_object.clone(),
+        )));
+    }
+}
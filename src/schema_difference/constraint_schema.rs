@@ -0,0 +1,62 @@
+use crate::queries::ConstraintSchema;
+
+use super::describe_part_change;
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for ConstraintSchema {
+    type Key = (String, String, String);
+
+    fn key(&self) -> Self::Key {
+        (
+            self.schema.clone(),
+            self.table_name.clone(),
+            self.name.clone(),
+        )
+    }
+
+    fn describe_changes(&self, after: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        if self.kind != after.kind {
+            changes.push(format!(
+                "changed kind from {:?} to {:?}",
+                self.kind, after.kind
+            ));
+        }
+        describe_part_change(&mut changes, "columns", &self.columns, &after.columns);
+        describe_part_change(
+            &mut changes,
+            "referenced table",
+            &self.referenced_table,
+            &after.referenced_table,
+        );
+        describe_part_change(
+            &mut changes,
+            "referenced columns",
+            &self.referenced_columns,
+            &after.referenced_columns,
+        );
+        describe_part_change(&mut changes, "ON UPDATE", &self.on_update, &after.on_update);
+        describe_part_change(&mut changes, "ON DELETE", &self.on_delete, &after.on_delete);
+        describe_part_change(
+            &mut changes,
+            "deferrable",
+            &self.is_deferrable,
+            &after.is_deferrable,
+        );
+        describe_part_change(
+            &mut changes,
+            "initially deferred",
+            &self.is_initially_deferred,
+            &after.is_initially_deferred,
+        );
+        describe_part_change(
+            &mut changes,
+            "definition",
+            &self.definition,
+            &after.definition,
+        );
+
+        changes
+    }
+}
@@ -0,0 +1,63 @@
+///
+/// How a single part of a schema object is shown within a change message.
+///
+pub(crate) trait DescribePart {
+    fn describe_part(&self) -> String;
+}
+
+impl DescribePart for String {
+    fn describe_part(&self) -> String {
+        format!("`{}`", self)
+    }
+}
+
+impl DescribePart for bool {
+    fn describe_part(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl DescribePart for i64 {
+    fn describe_part(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<T> DescribePart for Option<T>
+where
+    T: DescribePart,
+{
+    fn describe_part(&self) -> String {
+        match self {
+            Some(part) => part.describe_part(),
+            None => "none".to_string(),
+        }
+    }
+}
+
+impl DescribePart for Vec<String> {
+    fn describe_part(&self) -> String {
+        format!("({})", self.join(", "))
+    }
+}
+
+///
+/// Adds a message to `changes`, if the part differs between `before` and `after`.
+///
+pub(crate) fn describe_part_change<T>(
+    changes: &mut Vec<String>,
+    part_name: &str,
+    before: &T,
+    after: &T,
+) where
+    T: DescribePart + PartialEq,
+{
+    if before != after {
+        changes.push(format!(
+            "changed {} from {} to {}",
+            part_name,
+            before.describe_part(),
+            after.describe_part()
+        ));
+    }
+}
@@ -0,0 +1,21 @@
+use crate::queries::ExtensionSchema;
+
+use super::describe_part_change;
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for ExtensionSchema {
+    type Key = String;
+
+    fn key(&self) -> Self::Key {
+        self.name.clone()
+    }
+
+    fn describe_changes(&self, after: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        describe_part_change(&mut changes, "version", &self.version, &after.version);
+        describe_part_change(&mut changes, "schema", &self.schema, &after.schema);
+
+        changes
+    }
+}
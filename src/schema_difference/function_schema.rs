@@ -0,0 +1,43 @@
+use crate::queries::FunctionSchema;
+
+use super::describe_part_change;
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for FunctionSchema {
+    type Key = (String, String, String);
+
+    fn key(&self) -> Self::Key {
+        (
+            self.schema.clone(),
+            self.name.clone(),
+            self.arguments.clone(),
+        )
+    }
+
+    fn describe_changes(&self, after: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        describe_part_change(
+            &mut changes,
+            "return type",
+            &self.return_type,
+            &after.return_type,
+        );
+        describe_part_change(
+            &mut changes,
+            "procedure",
+            &self.is_procedure,
+            &after.is_procedure,
+        );
+        describe_part_change(&mut changes, "language", &self.language, &after.language);
+        describe_part_change(&mut changes, "body hash", &self.body_hash, &after.body_hash);
+        describe_part_change(
+            &mut changes,
+            "volatility",
+            &self.volatility,
+            &after.volatility,
+        );
+
+        changes
+    }
+}
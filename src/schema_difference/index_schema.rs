@@ -0,0 +1,38 @@
+use crate::queries::IndexSchema;
+
+use super::describe_part_change;
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for IndexSchema {
+    type Key = (String, String, String);
+
+    fn key(&self) -> Self::Key {
+        (
+            self.schema.clone(),
+            self.table_name.clone(),
+            self.name.clone(),
+        )
+    }
+
+    fn describe_changes(&self, after: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        describe_part_change(&mut changes, "columns", &self.columns, &after.columns);
+        describe_part_change(
+            &mut changes,
+            "include columns",
+            &self.include_columns,
+            &after.include_columns,
+        );
+        describe_part_change(
+            &mut changes,
+            "uniqueness",
+            &self.is_unique,
+            &after.is_unique,
+        );
+        describe_part_change(&mut changes, "method", &self.method, &after.method);
+        describe_part_change(&mut changes, "predicate", &self.predicate, &after.predicate);
+
+        changes
+    }
+}
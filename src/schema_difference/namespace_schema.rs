@@ -0,0 +1,11 @@
+use crate::queries::NamespaceSchema;
+
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for NamespaceSchema {
+    type Key = String;
+
+    fn key(&self) -> Self::Key {
+        self.name.clone()
+    }
+}
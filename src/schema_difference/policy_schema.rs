@@ -0,0 +1,38 @@
+use crate::queries::PolicySchema;
+
+use super::describe_part_change;
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for PolicySchema {
+    type Key = (String, String, String);
+
+    fn key(&self) -> Self::Key {
+        (
+            self.schema.clone(),
+            self.table_name.clone(),
+            self.name.clone(),
+        )
+    }
+
+    fn describe_changes(&self, after: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        describe_part_change(&mut changes, "command", &self.command, &after.command);
+        describe_part_change(
+            &mut changes,
+            "permissive",
+            &self.is_permissive,
+            &after.is_permissive,
+        );
+        describe_part_change(&mut changes, "roles", &self.roles, &after.roles);
+        describe_part_change(&mut changes, "USING", &self.using, &after.using);
+        describe_part_change(
+            &mut changes,
+            "WITH CHECK",
+            &self.with_check,
+            &after.with_check,
+        );
+
+        changes
+    }
+}
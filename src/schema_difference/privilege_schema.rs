@@ -0,0 +1,31 @@
+use crate::queries::PrivilegeSchema;
+
+use super::describe_part_change;
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for PrivilegeSchema {
+    type Key = (String, String, Option<String>, String, String);
+
+    fn key(&self) -> Self::Key {
+        (
+            self.schema.clone(),
+            self.table_name.clone(),
+            self.column_name.clone(),
+            self.grantee.clone(),
+            self.privilege.clone(),
+        )
+    }
+
+    fn describe_changes(&self, after: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        describe_part_change(
+            &mut changes,
+            "grantable",
+            &self.is_grantable,
+            &after.is_grantable,
+        );
+
+        changes
+    }
+}
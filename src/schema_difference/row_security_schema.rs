@@ -0,0 +1,21 @@
+use crate::queries::RowSecuritySchema;
+
+use super::describe_part_change;
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for RowSecuritySchema {
+    type Key = (String, String);
+
+    fn key(&self) -> Self::Key {
+        (self.schema.clone(), self.table_name.clone())
+    }
+
+    fn describe_changes(&self, after: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        describe_part_change(&mut changes, "enabled", &self.is_enabled, &after.is_enabled);
+        describe_part_change(&mut changes, "forced", &self.is_forced, &after.is_forced);
+
+        changes
+    }
+}
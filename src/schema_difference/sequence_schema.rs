@@ -0,0 +1,26 @@
+use crate::queries::SequenceSchema;
+
+use super::describe_part_change;
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for SequenceSchema {
+    type Key = (String, String);
+
+    fn key(&self) -> Self::Key {
+        (self.schema.clone(), self.name.clone())
+    }
+
+    fn describe_changes(&self, after: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        describe_part_change(&mut changes, "type", &self.data_type, &after.data_type);
+        describe_part_change(&mut changes, "start", &self.start_value, &after.start_value);
+        describe_part_change(&mut changes, "increment", &self.increment, &after.increment);
+        describe_part_change(&mut changes, "min value", &self.min_value, &after.min_value);
+        describe_part_change(&mut changes, "max value", &self.max_value, &after.max_value);
+        describe_part_change(&mut changes, "cycle", &self.is_cycle, &after.is_cycle);
+        describe_part_change(&mut changes, "owner", &self.owned_by, &after.owned_by);
+
+        changes
+    }
+}
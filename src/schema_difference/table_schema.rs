@@ -0,0 +1,161 @@
+use ::std::collections::BTreeMap;
+
+use crate::queries::ColumnSchema;
+use crate::queries::TableSchema;
+
+use super::describe_part_change;
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for TableSchema {
+    type Key = (String, String);
+
+    fn key(&self) -> Self::Key {
+        (self.schema.clone(), self.name.clone())
+    }
+
+    fn describe_changes(&self, after: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+        describe_part_change(&mut changes, "comment", &self.comment, &after.comment);
+        describe_part_change(
+            &mut changes,
+            "partition key",
+            &self.partition_key,
+            &after.partition_key,
+        );
+        describe_part_change(
+            &mut changes,
+            "partition bound",
+            &self.partition_bound,
+            &after.partition_bound,
+        );
+        describe_part_change(&mut changes, "inherits", &self.inherits, &after.inherits);
+
+        let mut after_columns: BTreeMap<&str, &ColumnSchema> = after
+            .columns
+            .iter()
+            .map(|column| (column.name.as_str(), column))
+            .collect();
+
+        for before_column in &self.columns {
+            match after_columns.remove(before_column.name.as_str()) {
+                None => changes.push(format!(
+                    "lost column `{}` ({})",
+                    before_column.name,
+                    describe_column(before_column)
+                )),
+                Some(after_column) => {
+                    describe_column_changes(before_column, after_column, &mut changes)
+                }
+            }
+        }
+
+        for after_column in after_columns.into_values() {
+            changes.push(format!(
+                "gained column `{}` ({})",
+                after_column.name,
+                describe_column(after_column)
+            ));
+        }
+
+        describe_part_change(
+            &mut changes,
+            "column order",
+            &shared_column_names(self, after),
+            &shared_column_names(after, self),
+        );
+
+        changes
+    }
+}
+
+fn describe_column_changes(before: &ColumnSchema, after: &ColumnSchema, changes: &mut Vec<String>) {
+    if before.data_type != after.data_type {
+        changes.push(format!(
+            "column `{}` changed type from {} to {}",
+            before.name, before.data_type, after.data_type
+        ));
+    }
+
+    if before.is_nullable != after.is_nullable {
+        changes.push(format!(
+            "column `{}` changed nullability from {} to {}",
+            before.name,
+            describe_nullability(before),
+            describe_nullability(after)
+        ));
+    }
+
+    let mut part_changes = Vec::new();
+    describe_part_change(
+        &mut part_changes,
+        "default",
+        &before.default,
+        &after.default,
+    );
+    describe_part_change(
+        &mut part_changes,
+        "identity",
+        &before.identity,
+        &after.identity,
+    );
+    describe_part_change(
+        &mut part_changes,
+        "generation",
+        &before.generation,
+        &after.generation,
+    );
+    describe_part_change(
+        &mut part_changes,
+        "collation",
+        &before.collation,
+        &after.collation,
+    );
+    describe_part_change(
+        &mut part_changes,
+        "comment",
+        &before.comment,
+        &after.comment,
+    );
+
+    changes.extend(
+        part_changes
+            .into_iter()
+            .map(|part_change| format!("column `{}` {}", before.name, part_change)),
+    );
+}
+
+///
+/// The names of the columns in `table`, which are also in `other`.
+/// Columns only in one table are left out, as they are reported as gained or lost.
+///
+fn shared_column_names(table: &TableSchema, other: &TableSchema) -> Vec<String> {
+    table
+        .columns
+        .iter()
+        .filter(|column| {
+            other
+                .columns
+                .iter()
+                .any(|other_column| other_column.name == column.name)
+        })
+        .map(|column| column.name.clone())
+        .collect()
+}
+
+fn describe_column(column: &ColumnSchema) -> String {
+    let mut description = format!("{}, {}", column.data_type, describe_nullability(column));
+    if let Some(default) = &column.default {
+        description.push_str(", DEFAULT ");
+        description.push_str(default);
+    }
+
+    description
+}
+
+fn describe_nullability(column: &ColumnSchema) -> &'static str {
+    if column.is_nullable {
+        "NULL"
+    } else {
+        "NOT NULL"
+    }
+}
@@ -0,0 +1,34 @@
+use crate::queries::TriggerSchema;
+
+use super::describe_part_change;
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for TriggerSchema {
+    type Key = (String, String, String);
+
+    fn key(&self) -> Self::Key {
+        (
+            self.schema.clone(),
+            self.table_name.clone(),
+            self.name.clone(),
+        )
+    }
+
+    fn describe_changes(&self, after: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        describe_part_change(
+            &mut changes,
+            "definition",
+            &self.definition,
+            &after.definition,
+        );
+        describe_part_change(&mut changes, "timing", &self.timing, &after.timing);
+        describe_part_change(&mut changes, "events", &self.events, &after.events);
+        describe_part_change(&mut changes, "function", &self.function, &after.function);
+        describe_part_change(&mut changes, "condition", &self.condition, &after.condition);
+        describe_part_change(&mut changes, "enabled", &self.is_enabled, &after.is_enabled);
+
+        changes
+    }
+}
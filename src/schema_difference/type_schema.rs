@@ -0,0 +1,69 @@
+use crate::queries::TypeSchema;
+
+use super::describe_part_change;
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for TypeSchema {
+    type Key = (String, String);
+
+    fn key(&self) -> Self::Key {
+        (self.schema.clone(), self.type_name.clone())
+    }
+
+    fn describe_changes(&self, after: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        if self.kind != after.kind {
+            changes.push(format!(
+                "changed kind from {:?} to {:?}",
+                self.kind, after.kind
+            ));
+        }
+
+        for before_value in &self.enum_values {
+            if !after.enum_values.contains(before_value) {
+                changes.push(format!("lost label `{}`", before_value));
+            }
+        }
+
+        for after_value in &after.enum_values {
+            if !self.enum_values.contains(after_value) {
+                changes.push(format!("gained label `{}`", after_value));
+            }
+        }
+
+        // Only report the order, if the same labels were shuffled around.
+        let before_kept = self
+            .enum_values
+            .iter()
+            .filter(|value| after.enum_values.contains(value));
+        let after_kept = after
+            .enum_values
+            .iter()
+            .filter(|value| self.enum_values.contains(value));
+        if !before_kept.eq(after_kept) {
+            changes.push(format!(
+                "reordered labels from ({}) to ({})",
+                self.enum_values.join(", "),
+                after.enum_values.join(", ")
+            ));
+        }
+
+        describe_part_change(&mut changes, "base type", &self.base_type, &after.base_type);
+        describe_part_change(&mut changes, "default", &self.default, &after.default);
+        describe_part_change(
+            &mut changes,
+            "constraints",
+            &self.constraints,
+            &after.constraints,
+        );
+        describe_part_change(
+            &mut changes,
+            "attributes",
+            &self.attributes,
+            &after.attributes,
+        );
+
+        changes
+    }
+}
@@ -0,0 +1,31 @@
+use crate::queries::ViewSchema;
+
+use super::describe_part_change;
+use super::SchemaObjectDiff;
+
+impl SchemaObjectDiff for ViewSchema {
+    type Key = (String, String);
+
+    fn key(&self) -> Self::Key {
+        (self.schema.clone(), self.name.clone())
+    }
+
+    fn describe_changes(&self, after: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        describe_part_change(
+            &mut changes,
+            "definition",
+            &self.definition,
+            &after.definition,
+        );
+        describe_part_change(
+            &mut changes,
+            "materialized",
+            &self.is_materialized,
+            &after.is_materialized,
+        );
+
+        changes
+    }
+}
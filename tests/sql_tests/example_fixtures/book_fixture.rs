@@ -0,0 +1,34 @@
+use ::assert_migrator_reversible::MigrationFixture;
+use ::sea_orm_migration::prelude::async_trait;
+use ::sea_orm_migration::sea_orm::ConnectionTrait;
+use ::sea_orm_migration::sea_orm::DatabaseConnection;
+use ::sea_orm_migration::sea_orm::DbErr;
+use ::sea_orm_migration::sea_orm::Statement;
+
+pub struct BookFixture;
+
+#[async_trait::async_trait]
+impl MigrationFixture for BookFixture {
+    async fn seed(&self, db_connection: &DatabaseConnection) -> Result<(), DbErr> {
+        db_connection
+            .execute_unprepared("INSERT INTO book (name) VALUES ('Dune')")
+            .await?;
+
+        Ok(())
+    }
+
+    async fn verify(&self, db_connection: &DatabaseConnection) -> Result<(), String> {
+        let maybe_book = db_connection
+            .query_one(Statement::from_string(
+                db_connection.get_database_backend(),
+                "SELECT name FROM book WHERE name = 'Dune'",
+            ))
+            .await
+            .map_err(|err| err.to_string())?;
+
+        match maybe_book {
+            Some(_) => Ok(()),
+            None => Err("book `Dune` is missing".to_string()),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+mod book_fixture;
+pub use self::book_fixture::*;
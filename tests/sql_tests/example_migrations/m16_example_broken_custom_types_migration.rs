@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                    CREATE DOMAIN positive_price AS integer DEFAULT 1 NOT NULL CHECK (VALUE > 0);
+                    CREATE TYPE address AS (street text, city varchar(100));
+                    CREATE TYPE price_range AS RANGE (subtype = integer);
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP TYPE price_range")
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                    ALTER TABLE book ENABLE ROW LEVEL SECURITY;
+                    CREATE POLICY book_read ON book FOR SELECT USING (id > 0);
+                    GRANT SELECT ON book TO PUBLIC;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE book DISABLE ROW LEVEL SECURITY")
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                    CREATE TABLE event (id integer NOT NULL, created_at date NOT NULL)
+                        PARTITION BY RANGE (created_at);
+                    CREATE TABLE event_2024 PARTITION OF event
+                        FOR VALUES FROM ('2024-01-01') TO ('2025-01-01');
+                    CREATE TABLE book_archive (archived_at date) INHERITS (book);
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP TABLE book_archive; DROP TABLE event;")
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                    ALTER TABLE event DETACH PARTITION event_2024;
+                    ALTER TABLE event ATTACH PARTITION event_2024
+                        FOR VALUES FROM ('2024-01-01') TO ('2024-07-01');
+                    ALTER TABLE book_archive NO INHERIT book;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE event DETACH PARTITION event_2024")
+            .await?;
+
+        Ok(())
+    }
+}
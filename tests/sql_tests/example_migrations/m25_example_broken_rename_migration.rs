@@ -0,0 +1,32 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE book RENAME COLUMN name TO title")
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                    ALTER TABLE book DROP COLUMN title;
+                    ALTER TABLE book ADD COLUMN name varchar NOT NULL DEFAULT '';
+                    ALTER TABLE book ALTER COLUMN name DROP DEFAULT;
+                    ALTER TABLE book ADD CONSTRAINT book_name_key UNIQUE (name);
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
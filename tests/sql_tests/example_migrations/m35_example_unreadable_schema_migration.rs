@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+///
+/// Leaves a table without any `CREATE TABLE` SQL in `sqlite_master`,
+/// so reading the schema afterwards fails.
+///
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                    CREATE TABLE shelf (id integer NOT NULL);
+                    PRAGMA writable_schema = ON;
+                    UPDATE sqlite_master SET sql = NULL WHERE name = 'shelf';
+                    PRAGMA writable_schema = OFF;
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP TABLE shelf")
+            .await?;
+
+        Ok(())
+    }
+}
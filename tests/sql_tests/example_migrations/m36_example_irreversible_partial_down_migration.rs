@@ -0,0 +1,34 @@
+use sea_orm_migration::prelude::*;
+
+///
+/// The `down` only undoes part of the `up`,
+/// so running `up` again afterwards fails.
+///
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                    CREATE TABLE shelf (id integer NOT NULL);
+                    CREATE TABLE bookcase (id integer NOT NULL);
+                "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP TABLE bookcase")
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DatabaseBackend::Postgres {
+            manager
+                .get_connection()
+                .execute_unprepared(
+                    r#"CREATE INDEX "idx-book-name" ON book (lower(name)) INCLUDE (id) WHERE id > 10"#,
+                )
+                .await?;
+
+            return Ok(());
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-book-name")
+                    .table(Book::Table)
+                    .col(Book::Name)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+pub enum Book {
+    Table,
+    Name,
+}
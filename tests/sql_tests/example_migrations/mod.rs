@@ -1,4 +1,39 @@
+pub mod m10_example_broken_sequence_migration;
+pub mod m11_example_broken_materialized_view_migration;
+pub mod m12_example_broken_function_migration;
+pub mod m13_example_broken_postgres_trigger_migration;
+pub mod m14_example_working_enum_migration;
+pub mod m15_example_broken_enum_order_migration;
+pub mod m16_example_broken_custom_types_migration;
+pub mod m17_example_broken_schema_migration;
+pub mod m18_example_broken_extension_migration;
+pub mod m19_example_broken_security_migration;
 pub mod m1_example_working_migration;
+pub mod m20_example_broken_comment_migration;
+pub mod m21_example_working_partition_migration;
+pub mod m22_example_broken_partition_migration;
+pub mod m23_example_broken_round_trip_migration;
+pub mod m24_example_working_rename_migration;
+pub mod m25_example_broken_rename_migration;
+pub mod m26_example_failing_down_migration;
+pub mod m27_example_working_legacy_table_migration;
+pub mod m28_example_irreversible_drop_legacy_table_migration;
+pub mod m29_example_broken_column_type_and_order_migration;
 pub mod m2_example_broken_migration;
 pub mod m2_example_working_migration;
+pub mod m30_example_working_partial_index_migration;
+pub mod m31_example_broken_partial_index_migration;
+pub mod m32_example_working_shelf_migration;
+pub mod m33_example_broken_clear_books_migration;
+pub mod m34_example_failing_up_migration;
+pub mod m35_example_unreadable_schema_migration;
+pub mod m36_example_irreversible_partial_down_migration;
+pub mod m3_example_broken_migration;
 pub mod m3_example_working_migration;
+pub mod m4_example_broken_column_order_migration;
+pub mod m4_example_broken_enum_migration;
+pub mod m5_example_broken_index_migration;
+pub mod m6_example_broken_view_migration;
+pub mod m7_example_broken_trigger_migration;
+pub mod m8_example_broken_foreign_key_migration;
+pub mod m9_example_broken_default_migration;
@@ -0,0 +1,22 @@
+use ::sea_orm_migration::prelude::async_trait;
+use ::sea_orm_migration::prelude::MigratorTrait;
+use ::sea_orm_migration::MigrationTrait;
+
+use crate::example_migrations;
+
+pub struct ExampleBrokenAfterIrreversibleMigrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for ExampleBrokenAfterIrreversibleMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(example_migrations::m1_example_working_migration::Migration),
+            Box::new(example_migrations::m2_example_working_migration::Migration),
+            Box::new(example_migrations::m27_example_working_legacy_table_migration::Migration),
+            Box::new(
+                example_migrations::m28_example_irreversible_drop_legacy_table_migration::Migration,
+            ),
+            Box::new(example_migrations::m3_example_broken_migration::Migration),
+        ]
+    }
+}
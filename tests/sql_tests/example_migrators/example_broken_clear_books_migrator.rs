@@ -0,0 +1,19 @@
+use ::sea_orm_migration::prelude::async_trait;
+use ::sea_orm_migration::prelude::MigratorTrait;
+use ::sea_orm_migration::MigrationTrait;
+
+use crate::example_migrations;
+
+pub struct ExampleBrokenClearBooksMigrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for ExampleBrokenClearBooksMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(example_migrations::m1_example_working_migration::Migration),
+            Box::new(example_migrations::m2_example_working_migration::Migration),
+            Box::new(example_migrations::m32_example_working_shelf_migration::Migration),
+            Box::new(example_migrations::m33_example_broken_clear_books_migration::Migration),
+        ]
+    }
+}
@@ -0,0 +1,21 @@
+use ::sea_orm_migration::prelude::async_trait;
+use ::sea_orm_migration::prelude::MigratorTrait;
+use ::sea_orm_migration::MigrationTrait;
+
+use crate::example_migrations;
+
+pub struct ExampleBrokenColumnTypeAndOrderMigrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for ExampleBrokenColumnTypeAndOrderMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(example_migrations::m1_example_working_migration::Migration),
+            Box::new(example_migrations::m2_example_working_migration::Migration),
+            Box::new(example_migrations::m3_example_working_migration::Migration),
+            Box::new(
+                example_migrations::m29_example_broken_column_type_and_order_migration::Migration,
+            ),
+        ]
+    }
+}
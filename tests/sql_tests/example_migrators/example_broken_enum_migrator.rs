@@ -0,0 +1,17 @@
+use ::sea_orm_migration::prelude::async_trait;
+use ::sea_orm_migration::prelude::MigratorTrait;
+use ::sea_orm_migration::MigrationTrait;
+
+use crate::example_migrations;
+
+pub struct ExampleBrokenEnumMigrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for ExampleBrokenEnumMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(example_migrations::m1_example_working_migration::Migration),
+            Box::new(example_migrations::m4_example_broken_enum_migration::Migration),
+        ]
+    }
+}
@@ -0,0 +1,18 @@
+use ::sea_orm_migration::prelude::async_trait;
+use ::sea_orm_migration::prelude::MigratorTrait;
+use ::sea_orm_migration::MigrationTrait;
+
+use crate::example_migrations;
+
+pub struct ExampleBrokenEnumOrderMigrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for ExampleBrokenEnumOrderMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(example_migrations::m1_example_working_migration::Migration),
+            Box::new(example_migrations::m14_example_working_enum_migration::Migration),
+            Box::new(example_migrations::m15_example_broken_enum_order_migration::Migration),
+        ]
+    }
+}
@@ -0,0 +1,19 @@
+use ::sea_orm_migration::prelude::async_trait;
+use ::sea_orm_migration::prelude::MigratorTrait;
+use ::sea_orm_migration::MigrationTrait;
+
+use crate::example_migrations;
+
+pub struct ExampleBrokenPartitionMigrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for ExampleBrokenPartitionMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(example_migrations::m1_example_working_migration::Migration),
+            Box::new(example_migrations::m2_example_working_migration::Migration),
+            Box::new(example_migrations::m21_example_working_partition_migration::Migration),
+            Box::new(example_migrations::m22_example_broken_partition_migration::Migration),
+        ]
+    }
+}
@@ -0,0 +1,19 @@
+use ::sea_orm_migration::prelude::async_trait;
+use ::sea_orm_migration::prelude::MigratorTrait;
+use ::sea_orm_migration::MigrationTrait;
+
+use crate::example_migrations;
+
+pub struct ExampleBrokenPostgresTriggerMigrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for ExampleBrokenPostgresTriggerMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(example_migrations::m1_example_working_migration::Migration),
+            Box::new(example_migrations::m2_example_working_migration::Migration),
+            Box::new(example_migrations::m12_example_broken_function_migration::Migration),
+            Box::new(example_migrations::m13_example_broken_postgres_trigger_migration::Migration),
+        ]
+    }
+}
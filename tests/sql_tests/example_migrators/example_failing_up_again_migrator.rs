@@ -0,0 +1,20 @@
+use ::sea_orm_migration::prelude::async_trait;
+use ::sea_orm_migration::prelude::MigratorTrait;
+use ::sea_orm_migration::MigrationTrait;
+
+use crate::example_migrations;
+
+pub struct ExampleFailingUpAgainMigrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for ExampleFailingUpAgainMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(example_migrations::m1_example_working_migration::Migration),
+            Box::new(example_migrations::m2_example_working_migration::Migration),
+            Box::new(
+                example_migrations::m36_example_irreversible_partial_down_migration::Migration,
+            ),
+        ]
+    }
+}
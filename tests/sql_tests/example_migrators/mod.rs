@@ -1,5 +1,98 @@
+mod example_broken_after_irreversible_migrator;
+pub use self::example_broken_after_irreversible_migrator::*;
+
+mod example_broken_before_irreversible_migrator;
+pub use self::example_broken_before_irreversible_migrator::*;
+
+mod example_broken_clear_books_migrator;
+pub use self::example_broken_clear_books_migrator::*;
+
+mod example_broken_column_migrator;
+pub use self::example_broken_column_migrator::*;
+
+mod example_broken_column_order_migrator;
+pub use self::example_broken_column_order_migrator::*;
+
+mod example_broken_column_type_and_order_migrator;
+pub use self::example_broken_column_type_and_order_migrator::*;
+
+mod example_broken_comment_migrator;
+pub use self::example_broken_comment_migrator::*;
+
+mod example_broken_custom_types_migrator;
+pub use self::example_broken_custom_types_migrator::*;
+
+mod example_broken_default_migrator;
+pub use self::example_broken_default_migrator::*;
+
+mod example_broken_enum_migrator;
+pub use self::example_broken_enum_migrator::*;
+
+mod example_broken_enum_order_migrator;
+pub use self::example_broken_enum_order_migrator::*;
+
+mod example_broken_extension_migrator;
+pub use self::example_broken_extension_migrator::*;
+
+mod example_broken_foreign_key_migrator;
+pub use self::example_broken_foreign_key_migrator::*;
+
+mod example_broken_function_migrator;
+pub use self::example_broken_function_migrator::*;
+
+mod example_broken_index_migrator;
+pub use self::example_broken_index_migrator::*;
+
+mod example_broken_materialized_view_migrator;
+pub use self::example_broken_materialized_view_migrator::*;
+
 mod example_broken_migrator;
 pub use self::example_broken_migrator::*;
 
+mod example_broken_partial_index_migrator;
+pub use self::example_broken_partial_index_migrator::*;
+
+mod example_broken_partition_migrator;
+pub use self::example_broken_partition_migrator::*;
+
+mod example_broken_postgres_trigger_migrator;
+pub use self::example_broken_postgres_trigger_migrator::*;
+
+mod example_broken_rename_migrator;
+pub use self::example_broken_rename_migrator::*;
+
+mod example_broken_round_trip_migrator;
+pub use self::example_broken_round_trip_migrator::*;
+
+mod example_broken_schema_migrator;
+pub use self::example_broken_schema_migrator::*;
+
+mod example_broken_security_migrator;
+pub use self::example_broken_security_migrator::*;
+
+mod example_broken_sequence_migrator;
+pub use self::example_broken_sequence_migrator::*;
+
+mod example_broken_trigger_migrator;
+pub use self::example_broken_trigger_migrator::*;
+
+mod example_broken_view_migrator;
+pub use self::example_broken_view_migrator::*;
+
+mod example_failing_down_migrator;
+pub use self::example_failing_down_migrator::*;
+
+mod example_failing_up_again_migrator;
+pub use self::example_failing_up_again_migrator::*;
+
+mod example_failing_up_migrator;
+pub use self::example_failing_up_migrator::*;
+
+mod example_unreadable_schema_migrator;
+pub use self::example_unreadable_schema_migrator::*;
+
 mod example_working_migrator;
 pub use self::example_working_migrator::*;
+
+mod example_working_rename_migrator;
+pub use self::example_working_rename_migrator::*;